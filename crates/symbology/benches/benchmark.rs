@@ -1,9 +1,38 @@
-use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use std::alloc::{GlobalAlloc, Layout, System};
 use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use symbology::figi::validate_bytes;
 use symbology::figi::Figi; // Ensure this path correctly points to the Figi type
 use symbology::figi_imperative::Figi as FigiImperative;
 use symbology::ibrk_figi::Figi as FigiIbrk;
 
+/// Counts heap allocations so the representation benches can report them.
+struct CountingAlloc;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+/// Run `f` once and return how many allocations it made.
+fn count_allocations<T>(f: impl FnOnce() -> T) -> usize {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    black_box(f());
+    ALLOCATIONS.load(Ordering::Relaxed) - before
+}
+
 fn bench_figi_ibrk(c: &mut Criterion) {
     c.bench_function("figi_ibrk", |b| {
         // Use a representative FIGI value for benchmarking
@@ -22,7 +51,7 @@ fn bench_figi_parse(c: &mut Criterion) {
         // This value should ideally cover typical use cases
         b.iter(|| {
             let figi = Figi::from_str(black_box("BBG000BLNNH6")).unwrap();
-            criterion::black_box(figi.0); // Prevent optimization
+            criterion::black_box(figi); // Prevent optimization
         })
     });
 }
@@ -33,15 +62,123 @@ fn bench_figi_imperative(c: &mut Criterion) {
         // This value should ideally cover typical use cases
         b.iter(|| {
             let figi = FigiImperative::from_str(black_box("BBG000BLNNH6")).unwrap();
-            criterion::black_box(figi.0); // Prevent optimization
+            criterion::black_box(figi); // Prevent optimization
+        })
+    });
+}
+
+fn bench_figi_par_parse_many(c: &mut Criterion) {
+    let inputs = vec!["BBG000BLNNH6"; 1_000_000];
+    c.bench_function("figi_par_parse_many", |b| {
+        b.iter(|| {
+            let figis = FigiIbrk::par_parse_many(black_box(&inputs));
+            criterion::black_box(figis); // Prevent optimization
+        })
+    });
+}
+
+fn bench_figi_validate_bytes(c: &mut Criterion) {
+    c.bench_function("figi_validate_bytes_valid", |b| {
+        b.iter(|| validate_bytes(black_box(b"BBG000BLNNH6")))
+    });
+    // Mostly failing on the prefix or position 3, with one valid input
+    let inputs: [&[u8]; 4] = [
+        b"BSG000BLNNH6",
+        b"BBX000BLNNH6",
+        b"GGG000BLNNH6",
+        b"BBG000BLNNH6",
+    ];
+    c.bench_function("figi_validate_bytes_invalid_heavy", |b| {
+        b.iter(|| {
+            for input in inputs {
+                let _ = criterion::black_box(validate_bytes(black_box(input)));
+            }
         })
     });
 }
 
+fn bench_figi_hashmap_insert(c: &mut Criterion) {
+    let figis: Vec<FigiIbrk> = FigiIbrk::enumerate_bodies(['B', 'B'])
+        .take(10_000)
+        .collect();
+    c.bench_function("figi_hashmap_insert", |b| {
+        b.iter(|| {
+            let mut map = std::collections::HashMap::with_capacity(figis.len());
+            for figi in black_box(&figis) {
+                map.insert(*figi, ());
+            }
+            criterion::black_box(map); // Prevent optimization
+        })
+    });
+}
+
+/// Parse and store with each FIGI representation:
+///
+/// - `string`: the winnow [`Figi`], backed by a `String`
+/// - `array`: the ibrk [`FigiIbrk`], 12 one-byte enums
+/// - `packed`: [`FigiIbrk`] packed into an 8-byte `PackedFigi`
+fn bench_figi_representation(c: &mut Criterion) {
+    const N: usize = 1_000_000;
+    let owned: Vec<String> = FigiIbrk::enumerate_bodies(['B', 'B'])
+        .take(N)
+        .map(|figi| String::from(&figi))
+        .collect();
+    let inputs: Vec<&str> = owned.iter().map(String::as_str).collect();
+
+    let parse_string =
+        || -> Vec<Figi> { inputs.iter().map(|s| Figi::from_str(s).unwrap()).collect() };
+    let parse_array = || -> Vec<FigiIbrk> {
+        inputs
+            .iter()
+            .map(|s| FigiIbrk::from_str(s).unwrap())
+            .collect()
+    };
+    let parse_packed = || -> Vec<_> {
+        inputs
+            .iter()
+            .map(|s| FigiIbrk::from_str(s).unwrap().pack())
+            .collect()
+    };
+
+    let mut single = c.benchmark_group("figi_repr_single");
+    single.bench_function("string", |b| {
+        b.iter(|| Figi::from_str(black_box("BBG000BLNNH6")).unwrap())
+    });
+    single.bench_function("array", |b| {
+        b.iter(|| FigiIbrk::from_str(black_box("BBG000BLNNH6")).unwrap())
+    });
+    single.bench_function("packed", |b| {
+        b.iter(|| {
+            FigiIbrk::from_str(black_box("BBG000BLNNH6"))
+                .unwrap()
+                .pack()
+        })
+    });
+    single.finish();
+
+    println!(
+        "figi_repr_1m allocations: string={} array={} packed={}",
+        count_allocations(parse_string),
+        count_allocations(parse_array),
+        count_allocations(parse_packed),
+    );
+    let mut many = c.benchmark_group("figi_repr_1m");
+    many.throughput(Throughput::Elements(N as u64));
+    many.sample_size(10);
+    many.bench_function("string", |b| b.iter(parse_string));
+    many.bench_function("array", |b| b.iter(parse_array));
+    many.bench_function("packed", |b| b.iter(parse_packed));
+    many.finish();
+}
+
 criterion_group!(
     benches,
     bench_figi_parse,
     bench_figi_ibrk,
-    bench_figi_imperative
+    bench_figi_imperative,
+    bench_figi_par_parse_many,
+    bench_figi_validate_bytes,
+    bench_figi_hashmap_insert,
+    bench_figi_representation
 );
 criterion_main!(benches);
@@ -0,0 +1,19 @@
+//! `figi::Figi::from_str` and `from_str_lenient` never panic, and a parsed
+//! FIGI's `Display` output parses strictly to the same value.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use symbology::figi::Figi;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(s) = std::str::from_utf8(data) else {
+        return;
+    };
+    if let Ok(figi) = s.parse::<Figi>() {
+        assert_eq!(figi.to_string().parse::<Figi>(), Ok(figi));
+    }
+    if let Ok(figi) = Figi::from_str_lenient(s) {
+        assert_eq!(figi.to_string().parse::<Figi>(), Ok(figi));
+    }
+});
@@ -0,0 +1,18 @@
+//! `ibrk_figi::Figi::from_str` never panics, and a parsed FIGI prints back to
+//! exactly its input, which parses to the same value.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use symbology::ibrk_figi::Figi;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(s) = std::str::from_utf8(data) else {
+        return;
+    };
+    if let Ok(figi) = s.parse::<Figi>() {
+        let printed = String::from(&figi);
+        assert_eq!(printed, s);
+        assert_eq!(printed.parse::<Figi>(), Ok(figi));
+    }
+});
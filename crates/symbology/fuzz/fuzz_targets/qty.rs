@@ -0,0 +1,23 @@
+//! `Qty::from_str` never panics. `Qty` has no `Display`, so the round trip is
+//! through the raw text kept by `parse_quantity_with_raw`, which must be the
+//! whole input and parse to the same value.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use symbology::scratch::{parse_quantity_with_raw, Qty};
+use winnow::prelude::*;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(s) = std::str::from_utf8(data) else {
+        return;
+    };
+    if let Ok(qty) = s.parse::<Qty>() {
+        let parsed = parse_quantity_with_raw
+            .parse(s)
+            .expect("`parse_quantity_with_raw` accepts what `Qty::from_str` does");
+        assert_eq!(parsed.raw, s);
+        assert_eq!(parsed.qty, qty);
+        assert_eq!(parsed.raw.parse::<Qty>(), Ok(qty));
+    }
+});
@@ -0,0 +1,128 @@
+use std::fmt;
+use std::str::FromStr;
+use winnow::ascii::digit1;
+use winnow::combinator::{alt, seq};
+use winnow::prelude::*;
+
+/// The asset class named by the two-letter prefix of a Bloomberg unique ID.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AssetClass {
+    /// `EQ`
+    Equity,
+    /// `BD`
+    Bond,
+    /// `IX`
+    Index,
+}
+
+impl AssetClass {
+    /// The two-letter code used as the prefix.
+    pub fn code(&self) -> &'static str {
+        match self {
+            AssetClass::Equity => "EQ",
+            AssetClass::Bond => "BD",
+            AssetClass::Index => "IX",
+        }
+    }
+}
+
+/// The legacy "BB Unique" identifier that predates the FIGI, e.g.
+/// `EQ0010080100001000`: an asset-class prefix followed by a numeric body.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BbUnique {
+    asset_class: AssetClass,
+    id: String,
+}
+
+impl BbUnique {
+    /// The asset class named by the prefix.
+    pub fn asset_class(&self) -> AssetClass {
+        self.asset_class
+    }
+
+    /// The numeric body after the prefix.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+impl fmt::Display for BbUnique {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.asset_class.code(), self.id)
+    }
+}
+
+impl FromStr for BbUnique {
+    type Err = String;
+
+    /// Parse a Bloomberg unique ID, rejecting prefixes other than the known
+    /// [`AssetClass`] codes.
+    ///
+    /// # Example
+    /// ```
+    /// use symbology::bbunique::{AssetClass, BbUnique};
+    ///
+    /// let id: BbUnique = "EQ0010080100001000".parse().unwrap();
+    /// assert_eq!(id.asset_class(), AssetClass::Equity);
+    /// assert_eq!(id.id(), "0010080100001000");
+    ///
+    /// assert!("ZZ0010080100001000".parse::<BbUnique>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        bb_unique.parse(s).map_err(|e| e.to_string())
+    }
+}
+
+fn asset_class(i: &mut &str) -> PResult<AssetClass> {
+    alt((
+        "EQ".value(AssetClass::Equity),
+        "BD".value(AssetClass::Bond),
+        "IX".value(AssetClass::Index),
+    ))
+    .parse_next(i)
+}
+
+fn bb_unique(i: &mut &str) -> PResult<BbUnique> {
+    seq! {
+        BbUnique {
+            asset_class: asset_class,
+            id: digit1.map(str::to_owned),
+        }
+    }
+    .parse_next(i)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_equity() {
+        let mut input = "EQ0010080100001000";
+        let expected = BbUnique {
+            asset_class: AssetClass::Equity,
+            id: "0010080100001000".to_owned(),
+        };
+        assert_eq!(bb_unique(&mut input), Ok(expected));
+    }
+
+    #[test]
+    fn test_bond() {
+        let id: BbUnique = "BD0000000012345678".parse().unwrap();
+        assert_eq!(id.asset_class(), AssetClass::Bond);
+        assert_eq!(id.id(), "0000000012345678");
+        assert_eq!(id.to_string(), "BD0000000012345678");
+    }
+
+    #[test]
+    fn test_unknown_prefix() {
+        assert!("ZZ0010080100001000".parse::<BbUnique>().is_err());
+        assert!("eq0010080100001000".parse::<BbUnique>().is_err());
+    }
+
+    #[test]
+    fn test_non_numeric_body() {
+        assert!("EQ".parse::<BbUnique>().is_err());
+        assert!("EQ00100801000010X0".parse::<BbUnique>().is_err());
+    }
+}
@@ -1,12 +1,15 @@
+use std::fmt;
 use std::str::FromStr;
-use winnow::combinator::{alt, seq};
+use winnow::ascii::Caseless;
+use winnow::combinator::{alt, opt, preceded, separated, separated_pair, seq};
 use winnow::prelude::*;
-#[derive(Debug, PartialEq)]
+use winnow::token::take_till;
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Scheme {
     BLP,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Provider {
     RefData,
     MktData,
@@ -15,32 +18,155 @@ pub enum Provider {
 
 // https://data.bloomberglp.com/professional/sites/10/2017/03/BLPAPI-Core-User-Guide.pdf
 // services are all in the form “//blp/<servicename>”.
+// Topics may follow as “/<topic>”, and subscription options as “?key=value&...”.
 #[derive(Debug, PartialEq)]
 pub struct Service {
     scheme: Scheme,
     provider: Provider,
+    topic: Option<String>,
+    query: Vec<(String, String)>,
+}
+
+impl Service {
+    /// The scheme before the service name, `blp` in `//blp/refdata`.
+    pub fn scheme(&self) -> &Scheme {
+        &self.scheme
+    }
+
+    /// The service name, `refdata` in `//blp/refdata`.
+    pub fn provider(&self) -> &Provider {
+        &self.provider
+    }
+
+    /// The topic after the service name, e.g. `ticker/IBM US Equity`.
+    pub fn topic(&self) -> Option<&str> {
+        self.topic.as_deref()
+    }
+
+    /// The `?key=value&...` subscription options, in the order given. Empty
+    /// when there are none.
+    pub fn query(&self) -> &[(String, String)] {
+        &self.query
+    }
+}
+
+impl fmt::Display for Scheme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::BLP => "blp",
+        })
+    }
+}
+
+impl fmt::Display for Provider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::RefData => "refdata",
+            Self::MktData => "mktdata",
+            Self::MktBar => "mktbar",
+        })
+    }
+}
+
+/// The scheme and service name are always in canonical lowercase, whatever the
+/// case of the parsed input; the topic and query are written as given.
+impl fmt::Display for Service {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "//{}/{}", self.scheme, self.provider)?;
+        if let Some(topic) = &self.topic {
+            write!(f, "/{topic}")?;
+        }
+        for (i, (key, value)) in self.query.iter().enumerate() {
+            let sep = if i == 0 { '?' } else { '&' };
+            write!(f, "{sep}{key}={value}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Service {
+    type Err = String;
+
+    /// Parse a service name of the form `//blp/<servicename>`, ignoring case,
+    /// optionally followed by a `/<topic>` and `?key=value&...` options.
+    ///
+    /// # Example
+    /// ```
+    /// use symbology::blpapi::{Provider, Service};
+    ///
+    /// let service: Service = "//blp/refdata".parse().unwrap();
+    /// assert_eq!(service.provider(), &Provider::RefData);
+    ///
+    /// let service: Service = "//BLP/RefData".parse().unwrap();
+    /// assert_eq!(service.to_string(), "//blp/refdata");
+    ///
+    /// let topic: Service = "//blp/mktdata/ticker/IBM US Equity?fields=BID,ASK".parse().unwrap();
+    /// assert_eq!(topic.topic(), Some("ticker/IBM US Equity"));
+    /// assert_eq!(topic.query(), [("fields".to_owned(), "BID,ASK".to_owned())]);
+    ///
+    /// assert!("//blp/unknown".parse::<Service>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        service.parse(s).map_err(|e| e.to_string())
+    }
 }
 
-fn scheme<'s>(i: &mut &'s str) -> PResult<Scheme> {
-    "blp".map(|_| Scheme::BLP).parse_next(i)
+/// Serialized as its canonical `Display` string and parsed back with `FromStr`.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Service {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Service {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
 }
 
-fn provider<'s>(i: &mut &'s str) -> PResult<Provider> {
+fn scheme(i: &mut &str) -> PResult<Scheme> {
+    Caseless("blp").map(|_| Scheme::BLP).parse_next(i)
+}
+
+fn provider(i: &mut &str) -> PResult<Provider> {
     alt((
-        "refdata".value(Provider::RefData),
-        "mktdata".value(Provider::MktData),
-        "mktbar".value(Provider::MktBar),
+        Caseless("refdata").value(Provider::RefData),
+        Caseless("mktdata").value(Provider::MktData),
+        Caseless("mktbar").value(Provider::MktBar),
     ))
     .parse_next(i)
 }
 
-fn service<'s>(i: &mut &'s str) -> PResult<Service> {
+fn topic(i: &mut &str) -> PResult<String> {
+    preceded('/', take_till(1.., '?'))
+        .map(str::to_owned)
+        .parse_next(i)
+}
+
+fn query_pair(i: &mut &str) -> PResult<(String, String)> {
+    separated_pair(take_till(1.., ('=', '&')), '=', take_till(0.., '&'))
+        .map(|(k, v): (&str, &str)| (k.to_owned(), v.to_owned()))
+        .parse_next(i)
+}
+
+fn query(i: &mut &str) -> PResult<Vec<(String, String)>> {
+    opt(preceded('?', separated(1.., query_pair, '&')))
+        .map(Option::unwrap_or_default)
+        .parse_next(i)
+}
+
+fn service(i: &mut &str) -> PResult<Service> {
     seq! {
         Service{
             _: "//",
             scheme: scheme,
             _: "/",
-            provider: provider
+            provider: provider,
+            topic: opt(topic),
+            query: query,
         }
     }
     .parse_next(i)
@@ -56,6 +182,8 @@ mod tests {
         let expected = Service {
             scheme: Scheme::BLP,
             provider: Provider::RefData,
+            topic: None,
+            query: vec![],
         };
         assert_eq!(service(&mut input), Ok(expected));
     }
@@ -66,6 +194,8 @@ mod tests {
         let expected = Service {
             scheme: Scheme::BLP,
             provider: Provider::MktData,
+            topic: None,
+            query: vec![],
         };
         assert_eq!(service(&mut input), Ok(expected));
     }
@@ -76,6 +206,8 @@ mod tests {
         let expected = Service {
             scheme: Scheme::BLP,
             provider: Provider::MktBar,
+            topic: None,
+            query: vec![],
         };
         assert_eq!(service(&mut input), Ok(expected));
     }
@@ -106,4 +238,89 @@ mod tests {
             "Missing scheme should not be parsed successfully."
         );
     }
+
+    #[test]
+    fn test_service_caseless() {
+        let mut input = "//BLP/REFDATA";
+        let expected = Service {
+            scheme: Scheme::BLP,
+            provider: Provider::RefData,
+            topic: None,
+            query: vec![],
+        };
+        assert_eq!(service(&mut input), Ok(expected));
+
+        let mut input = "//Blp/MktData";
+        let expected = Service {
+            scheme: Scheme::BLP,
+            provider: Provider::MktData,
+            topic: None,
+            query: vec![],
+        };
+        assert_eq!(service(&mut input), Ok(expected));
+    }
+
+    #[test]
+    fn test_service_display_is_lowercase() {
+        for input in ["//blp/mktbar", "//BLP/MKTBAR", "//bLp/MktBar"] {
+            assert_eq!(
+                input.parse::<Service>().unwrap().to_string(),
+                "//blp/mktbar"
+            );
+        }
+    }
+
+    #[test]
+    fn test_service_topic_with_query() {
+        let mut input = "//blp/mktdata/ticker/IBM US Equity?fields=BID,ASK&interval=2";
+        let expected = Service {
+            scheme: Scheme::BLP,
+            provider: Provider::MktData,
+            topic: Some("ticker/IBM US Equity".to_owned()),
+            query: vec![
+                ("fields".to_owned(), "BID,ASK".to_owned()),
+                ("interval".to_owned(), "2".to_owned()),
+            ],
+        };
+        assert_eq!(service(&mut input), Ok(expected));
+    }
+
+    #[test]
+    fn test_service_query_without_topic() {
+        let service: Service = "//blp/refdata?b=2&a=1".parse().unwrap();
+        assert_eq!(service.topic(), None);
+        assert_eq!(
+            service.query(),
+            [
+                ("b".to_owned(), "2".to_owned()),
+                ("a".to_owned(), "1".to_owned())
+            ]
+        );
+        assert_eq!(service.to_string(), "//blp/refdata?b=2&a=1");
+    }
+
+    #[test]
+    fn test_service_bare_has_empty_query() {
+        let service: Service = "//blp/refdata".parse().unwrap();
+        assert!(service.query().is_empty());
+        assert!("//blp/refdata?".parse::<Service>().is_err());
+        assert!("//blp/refdata?=1".parse::<Service>().is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_service_serde_round_trip() {
+        let service: Service = "//BLP/MktData/ticker/IBM US Equity?fields=BID,ASK"
+            .parse()
+            .unwrap();
+        let json = serde_json::to_string(&service).unwrap();
+        assert_eq!(
+            json,
+            "\"//blp/mktdata/ticker/IBM US Equity?fields=BID,ASK\""
+        );
+        assert_eq!(serde_json::from_str::<Service>(&json).unwrap(), service);
+
+        assert!(serde_json::from_str::<Service>("\"//blp/unknown\"").is_err());
+        assert!(serde_json::from_str::<Service>("42").is_err());
+    }
 }
@@ -0,0 +1,128 @@
+//! Collections keyed by FIGI, behind the `indexmap` feature.
+
+use indexmap::IndexMap;
+
+use crate::ibrk_figi::Figi;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// A map keyed by [`Figi`] that iterates in insertion order.
+pub struct FigiMap<V> {
+    inner: IndexMap<Figi, V>,
+}
+
+impl<V> Default for FigiMap<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V> FigiMap<V> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            inner: IndexMap::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            inner: IndexMap::with_capacity(capacity),
+        }
+    }
+
+    /// Insert a value, returning the previous one for `figi`. Replacing a value
+    /// keeps the key's original position.
+    pub fn insert(&mut self, figi: Figi, value: V) -> Option<V> {
+        self.inner.insert(figi, value)
+    }
+
+    #[must_use]
+    pub fn get(&self, figi: &Figi) -> Option<&V> {
+        self.inner.get(figi)
+    }
+
+    pub fn get_mut(&mut self, figi: &Figi) -> Option<&mut V> {
+        self.inner.get_mut(figi)
+    }
+
+    #[must_use]
+    pub fn contains_key(&self, figi: &Figi) -> bool {
+        self.inner.contains_key(figi)
+    }
+
+    /// Remove a value, shifting later entries down so the remaining order is
+    /// preserved.
+    pub fn remove(&mut self, figi: &Figi) -> Option<V> {
+        self.inner.shift_remove(figi)
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Entries in insertion order.
+    pub fn iter(&self) -> impl Iterator<Item = (&Figi, &V)> {
+        self.inner.iter()
+    }
+
+    /// Keys in insertion order.
+    pub fn keys(&self) -> impl Iterator<Item = &Figi> {
+        self.inner.keys()
+    }
+
+    /// Values in insertion order.
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.inner.values()
+    }
+}
+
+impl<V> FromIterator<(Figi, V)> for FigiMap<V> {
+    fn from_iter<I: IntoIterator<Item = (Figi, V)>>(iter: I) -> Self {
+        Self {
+            inner: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<V> IntoIterator for FigiMap<V> {
+    type Item = (Figi, V);
+    type IntoIter = indexmap::map::IntoIter<Figi, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insertion_order() {
+        let figis: Vec<Figi> = ["BBG000N88V36", "BBG000BD8ZK0", "BBG000B9XRY4"]
+            .iter()
+            .map(|s| s.parse().unwrap())
+            .collect();
+        let mut map = FigiMap::new();
+        for (i, figi) in figis.iter().enumerate() {
+            map.insert(*figi, i);
+        }
+        assert_eq!(map.keys().copied().collect::<Vec<_>>(), figis);
+
+        // Replacing keeps the position; removing keeps the order of the rest
+        assert_eq!(map.insert(figis[0], 10), Some(0));
+        assert_eq!(map.values().copied().collect::<Vec<_>>(), [10, 1, 2]);
+        assert_eq!(map.remove(&figis[1]), Some(1));
+        assert_eq!(
+            map.into_iter().collect::<Vec<_>>(),
+            [(figis[0], 10), (figis[2], 2)]
+        );
+    }
+}
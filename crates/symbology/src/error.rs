@@ -0,0 +1,69 @@
+//! A single error type for parsing any of the crate's identifiers.
+
+use crate::ibrk_figi::{InvalidFigi, InvalidOcrFigi};
+
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+/// Wraps the error of whichever identifier failed to parse, so mixed parsing
+/// code can use `?` with one error type. The wrapped error is available from
+/// [`std::error::Error::source`].
+pub enum ParseError {
+    /// A FIGI failed validation.
+    Figi(InvalidFigi),
+    /// A FIGI could not be recovered from OCR output.
+    FigiOcr(InvalidOcrFigi),
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match self {
+            Self::Figi(_) => write!(f, "Failed to parse FIGI"),
+            Self::FigiOcr(_) => write!(f, "Failed to recover FIGI from OCR output"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Figi(e) => Some(e),
+            Self::FigiOcr(e) => Some(e),
+        }
+    }
+}
+
+impl From<InvalidFigi> for ParseError {
+    fn from(value: InvalidFigi) -> Self {
+        Self::Figi(value)
+    }
+}
+
+impl From<InvalidOcrFigi> for ParseError {
+    fn from(value: InvalidOcrFigi) -> Self {
+        Self::FigiOcr(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ibrk_figi::Figi;
+    use std::error::Error;
+
+    fn parse_both(figi: &str, scanned: &str) -> Result<(Figi, Figi), ParseError> {
+        Ok((figi.parse()?, Figi::from_str_ocr(scanned)?))
+    }
+
+    #[test]
+    fn test_source_is_underlying_error() {
+        let err = parse_both("BBG000N88V37", "BBGOOON88V36").unwrap_err();
+        let expected = "BBG000N88V37".parse::<Figi>().unwrap_err();
+        assert_eq!(err, ParseError::Figi(expected.clone()));
+        assert_eq!(err.source().unwrap().to_string(), expected.to_string());
+
+        let err = parse_both("BBG000N88V36", "BBGOOOBD8ZK0").unwrap_err();
+        assert!(matches!(err, ParseError::FigiOcr(_)));
+        let source = err.source().unwrap();
+        assert!(source.downcast_ref::<InvalidOcrFigi>().is_some());
+    }
+}
@@ -0,0 +1,50 @@
+//! A C ABI for validating FIGIs from C, or from Python through a C extension.
+
+use crate::ibrk_figi::is_valid_bytes;
+
+/// Whether the `len` bytes at `ptr` are a valid FIGI.
+///
+/// Never allocates or panics. A null `ptr`, or any `len` other than 12,
+/// returns `false` without reading the buffer.
+///
+/// # Safety
+/// If `ptr` is not null and `len` is 12, `ptr` must point to 12 initialized
+/// bytes that stay readable for the duration of the call. The bytes need not
+/// be valid UTF-8 or NUL-terminated.
+#[no_mangle]
+pub unsafe extern "C" fn figi_is_valid(ptr: *const u8, len: usize) -> bool {
+    if ptr.is_null() || len != 12 {
+        return false;
+    }
+    // SAFETY: the caller guarantees `ptr` points to `len` readable bytes.
+    let bytes = unsafe { std::slice::from_raw_parts(ptr, len) };
+    is_valid_bytes(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_valid(b: &[u8]) -> bool {
+        unsafe { figi_is_valid(b.as_ptr(), b.len()) }
+    }
+
+    #[test]
+    fn test_figi_is_valid() {
+        assert!(is_valid(b"BBG000N88V36"));
+        assert!(!is_valid(b"BBG000N88V37"));
+        assert!(!is_valid(b"BSG000N88V36"));
+        assert!(!is_valid(b"bbg000n88v36"));
+        assert!(!is_valid(b"BBG000N88V3\xFF"));
+    }
+
+    #[test]
+    fn test_figi_is_valid_rejects_bad_buffers() {
+        assert!(!unsafe { figi_is_valid(std::ptr::null(), 12) });
+        assert!(!is_valid(b""));
+        assert!(!is_valid(b"BBG000N88V3"));
+        assert!(!is_valid(b"BBG000N88V366"));
+        // An overlong length is rejected before the buffer is read.
+        assert!(!unsafe { figi_is_valid(b"BBG000N88V36".as_ptr(), usize::MAX) });
+    }
+}
@@ -1,43 +1,305 @@
 //! Figi Identifier
 //! https://www.omg.org/spec/FIGI/1.1/Beta1/PDF
 
-use std::ops::RangeInclusive;
+use std::borrow::Cow;
+use std::ops::Deref;
 use std::str::FromStr;
+use winnow::ascii::Caseless;
+use winnow::combinator::cut_err;
+use winnow::error::ContextError;
+use winnow::error::ErrMode;
 use winnow::error::StrContext;
 use winnow::error::StrContextValue;
 use winnow::prelude::*;
+use winnow::token::any;
 use winnow::token::literal;
 use winnow::token::one_of;
 use winnow::token::take_while;
 
 use std::fmt;
 
+use crate::ibrk_figi::InvalidFigi;
+
 // NewType pattern inspired by https://www.worthe-it.co.za/blog/2020-10-31-newtype-pattern-in-rust.html
-#[derive(Debug, PartialEq)]
-pub struct Figi(pub String);
+/// A FIGI string and the prefix branch that matched while parsing it.
+///
+/// Every constructor in this module stores the canonical uppercase form: the
+/// strict parsers only accept uppercase, and the lenient ones uppercase before
+/// storing. Equality is therefore byte-exact and agrees with FIGI identity, so
+/// a FIGI parsed leniently from `bbg000blnnh6` equals one parsed strictly from
+/// `BBG000BLNNH6`. Use [`Figi::eq_ignore_ascii_case`] to compare against text
+/// of unknown case.
+///
+/// The fields are private so that every `Figi` went through a parser: the
+/// string is always a structurally valid 12-character FIGI and the provider
+/// always matches its prefix. Read the string through `Deref` or `AsRef<str>`.
+///
+/// ```compile_fail
+/// use symbology::figi::{CertifiedProvider, Figi};
+///
+/// let figi = Figi("BBG000BLNNH6".into(), CertifiedProvider::Other(*b"XC"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Figi(String, CertifiedProvider);
+
+/// Which branch of the prefix grammar matched while parsing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CertifiedProvider {
+    /// The `BBG` fast path: issued by Bloomberg.
+    Bloomberg,
+    /// Any other certified provider, identified by its two-consonant prefix.
+    Other([u8; 2]),
+}
 
 impl FromStr for Figi {
     type Err = String;
 
+    /// Parse a FIGI, rejecting anything that is not exactly a valid FIGI.
+    ///
+    /// # Example
+    /// ```
+    /// use symbology::figi::Figi;
+    ///
+    /// let figi: Figi = "BBG000BLNNH6".parse().unwrap();
+    /// assert_eq!(figi.to_string(), "BBG000BLNNH6");
+    ///
+    /// assert!("BSG000BLNNH6".parse::<Figi>().is_err());
+    /// ```
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut bytes_slice = s.as_bytes();
+        let bytes_slice = s.as_bytes();
         // Now that we have a [u8; 12], we can pass it to the parser
         // Assuming `parse_figi` is adapted to work with a fixed-size byte array
-        match parse_figi.parse(&mut bytes_slice) {
-            Ok(_) => {
-                Ok(Figi(s.to_owned())) // If parsing succeeds, create a Figi instance
+        match parse_figi.parse(bytes_slice) {
+            Ok((provider, _)) => {
+                Ok(Figi(s.to_owned(), provider)) // If parsing succeeds, create a Figi instance
             }
             Err(_) => Err(String::from("Failed to parse FIGI")), // Handle parsing errors appropriately
         }
     }
 }
 
+/// Like [`FromStr`], but keeps the caller's `String` instead of copying it.
+impl TryFrom<String> for Figi {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        match parse_figi.parse(s.as_bytes()) {
+            Ok((provider, _)) => Ok(Figi(s, provider)),
+            Err(_) => Err(String::from("Failed to parse FIGI")),
+        }
+    }
+}
+
+impl Figi {
+    /// Parse a FIGI after [`normalize`]-ing it, so case, surrounding
+    /// whitespace, a byte order mark and surrounding quotes are ignored. The
+    /// canonical uppercase form is stored.
+    pub fn from_str_lenient(s: &str) -> Result<Self, String> {
+        let s = normalize(s);
+        let provider = match parse_figi.parse(s.as_bytes()) {
+            Ok((provider, _)) => provider,
+            Err(_) => return Err(String::from("Failed to parse FIGI")),
+        };
+        Ok(Figi(s.into_owned(), provider))
+    }
+
+    /// Whether `other` spells this FIGI, ignoring ASCII case.
+    pub fn eq_ignore_ascii_case(&self, other: &str) -> bool {
+        self.0.eq_ignore_ascii_case(other)
+    }
+
+    /// The certified provider recorded when the prefix was parsed.
+    pub fn certified_provider(&self) -> CertifiedProvider {
+        self.1
+    }
+
+    /// The first three characters: the two-consonant provider prefix and `G`.
+    /// Agrees with [`crate::ibrk_figi::Figi::prefix3`].
+    pub fn prefix3(&self) -> &str {
+        &self.0[..3]
+    }
+
+    /// Parse a FIGI with an optional `@` exchange qualifier, as in
+    /// `BBG000N88V36@UN`. The qualifier must be one or more uppercase ASCII
+    /// letters or digits.
+    ///
+    /// # Example
+    /// ```
+    /// use symbology::figi::Figi;
+    ///
+    /// let q = Figi::parse_qualified("BBG000N88V36@UN").unwrap();
+    /// assert_eq!(q.figi.to_string(), "BBG000N88V36");
+    /// assert_eq!(q.exchange.as_deref(), Some("UN"));
+    ///
+    /// assert!(Figi::parse_qualified("BBG000N88V36@un").is_err());
+    /// ```
+    pub fn parse_qualified(s: &str) -> Result<QualifiedFigi, String> {
+        let (base, exchange) = match s.split_once('@') {
+            Some((base, exchange)) => (base, Some(exchange)),
+            None => (s, None),
+        };
+        let figi = Figi::from_str(base)?;
+        if let Some(exchange) = exchange {
+            let valid = !exchange.is_empty()
+                && exchange
+                    .bytes()
+                    .all(|b| b.is_ascii_uppercase() || b.is_ascii_digit());
+            if !valid {
+                return Err(format!("Invalid exchange qualifier {exchange:?}"));
+            }
+        }
+        Ok(QualifiedFigi {
+            figi,
+            exchange: exchange.map(str::to_owned),
+        })
+    }
+}
+
+/// A FIGI with the exchange qualifier some systems append, as parsed by
+/// [`Figi::parse_qualified`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QualifiedFigi {
+    /// The FIGI before the `@`.
+    pub figi: Figi,
+    /// The exchange qualifier after the `@`, e.g. `UN`, or `None` if the input
+    /// had no `@` suffix.
+    pub exchange: Option<String>,
+}
+
 impl fmt::Display for Figi {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.0)
+        f.write_str(&self.0)
+    }
+}
+
+/// A [`Figi`] stored without a heap allocation: the provider stands in for the
+/// shared prefix (almost always `BBG`), and only the 9 characters after it are
+/// kept. Position 3 is always `G` and is not stored.
+///
+/// # Example
+/// ```
+/// use symbology::figi::{CompactFigi, Figi};
+///
+/// let figi: Figi = "BBG000BLNNH6".parse().unwrap();
+/// let compact = CompactFigi::from(&figi);
+/// assert_eq!(compact.full(), "BBG000BLNNH6");
+/// assert_eq!(Figi::from(compact), figi);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CompactFigi {
+    provider: CertifiedProvider,
+    suffix: [u8; 9],
+}
+
+impl CompactFigi {
+    /// The certified provider, which determines the first two characters.
+    pub fn certified_provider(&self) -> CertifiedProvider {
+        self.provider
+    }
+
+    /// Reconstruct the full 12-character FIGI.
+    pub fn full(&self) -> String {
+        let mut s = String::with_capacity(12);
+        s.extend(self.prefix().iter().map(|b| char::from(*b)));
+        s.push('G');
+        s.extend(self.suffix.iter().map(|b| char::from(*b)));
+        s
+    }
+
+    fn prefix(&self) -> [u8; 2] {
+        match self.provider {
+            CertifiedProvider::Bloomberg => *b"BB",
+            CertifiedProvider::Other(p) => p,
+        }
+    }
+}
+
+impl From<&Figi> for CompactFigi {
+    fn from(figi: &Figi) -> Self {
+        let mut suffix = [0; 9];
+        suffix.copy_from_slice(&figi.0.as_bytes()[3..]);
+        CompactFigi {
+            provider: figi.1,
+            suffix,
+        }
+    }
+}
+
+impl From<CompactFigi> for Figi {
+    fn from(compact: CompactFigi) -> Self {
+        Figi(compact.full(), compact.provider)
+    }
+}
+
+impl fmt::Display for CompactFigi {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.full())
+    }
+}
+
+/// Canonicalize raw input for the lenient parsers: trim whitespace, strip a
+/// leading UTF-8 byte order mark and one pair of surrounding single or double
+/// quotes (as found in spreadsheet CSV exports), and uppercase.
+///
+/// Input that only needs slicing is borrowed; a copy is made only to change
+/// case.
+pub fn normalize(s: &str) -> Cow<'_, str> {
+    let s = strip_export_noise(s.trim()).trim();
+    if s.bytes().any(|b| b.is_ascii_lowercase()) {
+        Cow::Owned(s.to_ascii_uppercase())
+    } else {
+        Cow::Borrowed(s)
     }
 }
 
+/// Strip a leading byte order mark, then one pair of matching quotes.
+fn strip_export_noise(s: &str) -> &str {
+    let s = s.strip_prefix('\u{FEFF}').unwrap_or(s);
+    ['"', '\'']
+        .iter()
+        .find_map(|q| s.strip_prefix(*q)?.strip_suffix(*q))
+        .unwrap_or(s)
+}
+
+/// Read-only view of the FIGI as a `&str`.
+///
+/// Only `Deref` is provided (no `DerefMut`), so the validated string cannot be
+/// mutated through it.
+impl Deref for Figi {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for Figi {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Validate a FIGI held as raw bytes, such as a slice of memory-mapped data,
+/// without converting it to `&str` first. Unlike [`Figi`]'s parsers, this
+/// verifies the check digit.
+///
+/// # Errors
+/// The first rule `b` breaks, in the same order as
+/// [`crate::ibrk_figi::Figi::from_chars`]: a slice that is not 12 bytes long
+/// is [`InvalidFigi::Length`], and a non-ASCII byte fails the character rule
+/// for its position.
+///
+/// # Example
+/// ```
+/// use symbology::figi::validate_bytes;
+///
+/// assert!(validate_bytes(b"BBG000BLNNH6").is_ok());
+/// assert!(validate_bytes(b"BBG000BLNNH7").is_err());
+/// ```
+pub fn validate_bytes(b: &[u8]) -> Result<(), InvalidFigi> {
+    crate::ibrk_figi::Figi::from_bytes(b).map(|_| ())
+}
+
 #[inline(always)]
 fn is_consonant(b: u8) -> bool {
     matches!(b, b'B'..=b'D' | b'F'..=b'H' | b'J'..=b'N' | b'P'..=b'T' | b'V'..=b'Z')
@@ -50,7 +312,7 @@ fn is_conso_numeric(b: u8) -> bool {
 
 fn is_valid_prefix(input: &[u8]) -> bool {
     match input {
-        b"BS" | b"BM" | b"GG" | b"GB" | b"GH" | b"KY" | b"VG" => false,
+        [c1, c2] => !crate::ibrk_figi::is_reserved_prefix(char::from(*c1), char::from(*c2)),
         _ => true,
     }
 }
@@ -68,40 +330,330 @@ pub(crate) unsafe fn from_utf8_unchecked<'b>(
 }
 
 #[inline(always)]
-fn prefix<'s>(input: &mut &'s [u8]) -> PResult<&'s [u8]> {
+fn prefix(input: &mut &[u8]) -> PResult<CertifiedProvider> {
     // Almost all Figi's are issued by Bloomberg and start with "BB"
     // Optimistic parsing here nets 17% performance gain
     use winnow::combinator::alt;
     alt((
-        literal(b"BBG").void(),
+        literal(b"BBG").value(CertifiedProvider::Bloomberg),
+        prefix_generic,
+    ))
+    .parse_next(input)
+}
+
+/// [`prefix`] without the optimistic `BBG` branch. `BB` still maps to
+/// [`CertifiedProvider::Bloomberg`] so both paths agree.
+fn prefix_generic(input: &mut &[u8]) -> PResult<CertifiedProvider> {
+    (
+        take_while(2usize, is_consonant).verify(is_valid_prefix),
+        b'G',
+    )
+        .map(|(p, _): (&[u8], u8)| match p {
+            b"BB" => CertifiedProvider::Bloomberg,
+            _ => CertifiedProvider::Other([p[0], p[1]]),
+        })
+        .context(StrContext::Expected(StrContextValue::Description(
+            "Two valid consonants  follow by a 'G'",
+        )))
+        .parse_next(input)
+}
+
+fn parse_figi<'s>(input: &mut &'s [u8]) -> PResult<(CertifiedProvider, &'s str)> {
+    parse_figi_after(prefix).parse_next(input)
+}
+
+/// The rest of a FIGI after `prefix`, recognizing the whole code.
+fn parse_figi_after<'s>(
+    prefix: impl Parser<&'s [u8], CertifiedProvider, ContextError>,
+) -> impl Parser<&'s [u8], (CertifiedProvider, &'s str), ContextError> {
+    (
+        prefix,
+        take_while(8usize, is_conso_numeric).context(StrContext::Expected(
+            StrContextValue::Description("Eight consonant or numeric characters"),
+        )),
+        one_of(b'0'..=b'9').context(StrContext::Expected(StrContextValue::Description(
+            "Check digit",
+        ))),
+    )
+        .with_recognized()
+        .map(|((provider, _, _), b)| {
+            let s = unsafe { from_utf8_unchecked(b, "`is_unquoted_char` filters out on-ASCII") };
+            (provider, s)
+        })
+}
+
+/// Parse a FIGI from a `&str` stream, for use inside larger winnow grammars.
+///
+/// Like every winnow combinator, this stops after the FIGI and leaves any
+/// trailing input unconsumed; use [`figi_complete`] to reject it.
+///
+/// # Example
+/// ```
+/// use symbology::figi::{figi, Figi};
+/// use winnow::ascii::digit1;
+/// use winnow::combinator::seq;
+/// use winnow::prelude::*;
+///
+/// struct Order {
+///     figi: Figi,
+///     qty: u32,
+/// }
+///
+/// let order = seq! {
+///     Order {
+///         _: "BUY ",
+///         figi: figi,
+///         _: " ",
+///         qty: digit1.parse_to(),
+///     }
+/// }
+/// .parse("BUY BBG000BLNNH6 100")
+/// .unwrap();
+/// assert_eq!(order.figi.to_string(), "BBG000BLNNH6");
+/// assert_eq!(order.qty, 100);
+/// ```
+pub fn figi(input: &mut &str) -> PResult<Figi> {
+    let mut bytes = input.as_bytes();
+    let (provider, s) = parse_figi.parse_next(&mut bytes)?;
+    // A successful parse only consumes ASCII, so this is a char boundary
+    *input = &input[s.len()..];
+    Ok(Figi(s.to_owned(), provider))
+}
+
+/// [`figi`] over the whole of `s`, rejecting trailing input.
+///
+/// # Example
+/// ```
+/// use symbology::figi::figi_complete;
+///
+/// assert!(figi_complete("BBG000BLNNH6").is_ok());
+/// assert!(figi_complete("BBG000BLNNH6 100").is_err());
+/// ```
+pub fn figi_complete(s: &str) -> Result<Figi, String> {
+    figi.parse(s).map_err(|e| e.to_string())
+}
+
+/// Scan forward for the first FIGI delimited by non-alphanumeric characters
+/// or the ends of the input, returning its byte offset from where the scan
+/// started. Input up to the end of the FIGI is consumed.
+///
+/// # Example
+/// ```
+/// use symbology::figi::figi_in_context;
+/// use winnow::prelude::*;
+///
+/// let mut input = "FIGI=BBG000N88V36;TICKER=AAPL";
+/// let (offset, figi) = figi_in_context(&mut input).unwrap();
+/// assert_eq!((offset, figi.to_string().as_str()), (5, "BBG000N88V36"));
+/// assert_eq!(input, ";TICKER=AAPL");
+/// ```
+pub fn figi_in_context(input: &mut &str) -> PResult<(usize, Figi)> {
+    let start = *input;
+    let mut rest = start;
+    let mut at_boundary = true;
+    while let Some(c) = rest.chars().next() {
+        if at_boundary {
+            let mut candidate = rest;
+            if let Ok(found) = figi(&mut candidate) {
+                if !candidate.starts_with(|c: char| c.is_alphanumeric()) {
+                    *input = candidate;
+                    return Ok((start.len() - rest.len(), found));
+                }
+            }
+        }
+        at_boundary = !c.is_alphanumeric();
+        rest = &rest[c.len_utf8()..];
+    }
+    Err(ErrMode::Backtrack(ContextError::new()))
+}
+
+/// Every FIGI [`figi_in_context`] finds in `s`, with byte offsets into `s`.
+pub fn figis_in_context(s: &str) -> Vec<(usize, Figi)> {
+    let mut input = s;
+    let mut found = Vec::new();
+    loop {
+        let consumed = s.len() - input.len();
+        match figi_in_context(&mut input) {
+            Ok((offset, figi)) => found.push((consumed + offset, figi)),
+            Err(_) => return found,
+        }
+    }
+}
+
+/// Like [`figi`], but without the optimistic `BBG` branch: every prefix goes
+/// through the general two-consonants-and-`G` rule. The result is always the
+/// same as [`figi`]'s; this exists to test that both paths agree.
+///
+/// Trailing input is left unconsumed; see [`parse_figi_generic_complete`].
+pub fn parse_figi_generic(input: &mut &str) -> PResult<Figi> {
+    let mut bytes = input.as_bytes();
+    let (provider, s) = parse_figi_after(prefix_generic).parse_next(&mut bytes)?;
+    // A successful parse only consumes ASCII, so this is a char boundary
+    *input = &input[s.len()..];
+    Ok(Figi(s.to_owned(), provider))
+}
+
+/// [`parse_figi_generic`] over the whole of `s`, rejecting trailing input.
+pub fn parse_figi_generic_complete(s: &str) -> Result<Figi, String> {
+    parse_figi_generic.parse(s).map_err(|e| e.to_string())
+}
+
+/// Like [`figi`], but ASCII case-insensitive. The stored FIGI is uppercase.
+///
+/// Trailing input is left unconsumed; see [`figi_lenient_complete`].
+pub fn figi_lenient(input: &mut &str) -> PResult<Figi> {
+    let mut bytes = input.as_bytes();
+    let (provider, s) = parse_figi_lenient.parse_next(&mut bytes)?;
+    // A successful parse only consumes ASCII, so this is a char boundary
+    *input = &input[s.len()..];
+    Ok(Figi(s, provider))
+}
+
+/// [`figi_lenient`] over the whole of `s`, rejecting trailing input. Unlike
+/// [`Figi::from_str_lenient`], surrounding whitespace and quotes are not
+/// stripped.
+pub fn figi_lenient_complete(s: &str) -> Result<Figi, String> {
+    figi_lenient.parse(s).map_err(|e| e.to_string())
+}
+
+#[inline(always)]
+fn is_consonant_caseless(b: u8) -> bool {
+    is_consonant(b.to_ascii_uppercase())
+}
+
+#[inline(always)]
+fn is_conso_numeric_caseless(b: u8) -> bool {
+    is_conso_numeric(b.to_ascii_uppercase())
+}
+
+fn prefix_lenient(input: &mut &[u8]) -> PResult<CertifiedProvider> {
+    use winnow::combinator::alt;
+    alt((
+        literal(Caseless(b"BBG")).value(CertifiedProvider::Bloomberg),
         (
-            take_while(2usize, is_consonant).verify(is_valid_prefix),
-            b'G',
+            take_while(2usize, is_consonant_caseless)
+                .verify(|p: &[u8]| is_valid_prefix(&p.to_ascii_uppercase())),
+            literal(Caseless(b'G')),
         )
-            .void(),
+            .map(|(p, _): (&[u8], &[u8])| {
+                CertifiedProvider::Other([p[0].to_ascii_uppercase(), p[1].to_ascii_uppercase()])
+            }),
     ))
     .context(StrContext::Expected(StrContextValue::Description(
         "Two valid consonants  follow by a 'G'",
     )))
-    .recognize()
     .parse_next(input)
 }
 
-fn parse_figi<'s>(input: &mut &'s [u8]) -> PResult<&'s str> {
+/// Same grammar as `parse_figi` but ASCII case-insensitive; the match is
+/// normalized to uppercase.
+fn parse_figi_lenient(input: &mut &[u8]) -> PResult<(CertifiedProvider, String)> {
     (
-        prefix,
-        take_while(8usize, is_conso_numeric).context(StrContext::Expected(
+        prefix_lenient,
+        take_while(8usize, is_conso_numeric_caseless).context(StrContext::Expected(
             StrContextValue::Description("Eight consonant or numeric characters"),
         )),
         one_of(b'0'..=b'9').context(StrContext::Expected(StrContextValue::Description(
             "Check digit",
         ))),
     )
-        .recognize()
-        .map(|b| unsafe { from_utf8_unchecked(b, "`is_unquoted_char` filters out on-ASCII") })
+        .with_recognized()
+        .map(|((provider, _, _), b): (_, &[u8])| {
+            let s = unsafe {
+                from_utf8_unchecked(b, "`is_conso_numeric_caseless` filters out non-ASCII")
+            };
+            (provider, s.to_ascii_uppercase())
+        })
         .parse_next(input)
 }
 
+/// One structural problem found by [`parse_figi_verbose`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FigiDiagnostic {
+    /// The input is this many characters instead of 12.
+    Length(usize),
+    /// The character at `position` (1-based) is not what the grammar expects.
+    Position {
+        /// The 1-based character position, counting characters rather than
+        /// bytes.
+        position: usize,
+        /// What the grammar expects there, e.g. `"Consonant"` or `"'G'"`.
+        expected: &'static str,
+    },
+}
+
+/// The rule for one position (1-based) of the FIGI grammar, and how a
+/// [`FigiDiagnostic::Position`] describes it. The same predicates back
+/// `parse_figi`.
+fn position_rule(position: usize) -> (fn(u8) -> bool, &'static str) {
+    match position {
+        1 | 2 => (is_consonant, "Consonant"),
+        3 => (|b| b == b'G', "'G'"),
+        4..=11 => (is_conso_numeric, "Consonant or numeric character"),
+        _ => (|b| b.is_ascii_digit(), "Check digit"),
+    }
+}
+
+/// One character of a FIGI at `position`. A mismatch is a cut error whose
+/// context is the expectation, so [`parse_figi_verbose`] can record it and
+/// resume at the next position.
+fn figi_char<'s>(position: usize) -> impl Parser<&'s str, char, ContextError> {
+    let (rule, expected) = position_rule(position);
+    cut_err(one_of(move |c: char| c.is_ascii() && rule(c as u8)))
+        .context(StrContext::Expected(StrContextValue::Description(expected)))
+}
+
+/// Parse a FIGI, reporting every structural problem instead of stopping at the
+/// first.
+///
+/// Each of the first 12 characters is parsed against the rule for its
+/// position; on a mismatch the expectation is recorded and parsing resumes
+/// after that character, so a doubly broken input yields both positions.
+/// Position diagnostics are in position order, after any
+/// [`FigiDiagnostic::Length`].
+pub fn parse_figi_verbose(s: &str) -> Result<Figi, Vec<FigiDiagnostic>> {
+    let mut diagnostics = Vec::new();
+    let count = s.chars().count();
+    if count != 12 {
+        diagnostics.push(FigiDiagnostic::Length(count));
+    }
+    let mut input = s;
+    let mut prefix = [0u8; 2];
+    for position in 1..=count.min(12) {
+        match figi_char(position).parse_next(&mut input) {
+            Ok(c) if position <= 2 => prefix[position - 1] = c as u8,
+            Ok(_) => {}
+            Err(e) => {
+                let expected = e.into_inner().and_then(|e| {
+                    e.context().find_map(|c| match c {
+                        StrContext::Expected(StrContextValue::Description(d)) => Some(*d),
+                        _ => None,
+                    })
+                });
+                diagnostics.extend(
+                    expected.map(|expected| FigiDiagnostic::Position { position, expected }),
+                );
+                // Recover by skipping the offending character
+                let _: PResult<char> = any.parse_next(&mut input);
+            }
+        }
+        // Both consonants are known once position 2 is parsed, and any
+        // diagnostic recorded so far is for position 1 or 2
+        if position == 2 && prefix.iter().all(|b| is_consonant(*b)) && !is_valid_prefix(&prefix) {
+            diagnostics.push(FigiDiagnostic::Position {
+                position: 1,
+                expected: "Prefix other than BS, BM, GG, GB, GH, KY or VG",
+            });
+        }
+    }
+    if diagnostics.is_empty() {
+        Figi::from_str(s).map_err(|_| diagnostics)
+    } else {
+        Err(diagnostics)
+    }
+}
+
 #[cfg(test)]
 mod exhaustive_tests {
     use super::*;
@@ -271,4 +823,376 @@ mod exhaustive_tests {
             );
         }
     }
+
+    #[test]
+    fn lenient_lowercase() {
+        let (_, result) = parse_figi_lenient
+            .parse(b"bbg000blnnh6".as_slice())
+            .unwrap();
+        assert_eq!(result, "BBG000BLNNH6");
+
+        let result = Figi::from_str_lenient("xcG00gfxxMR3").unwrap();
+        assert_eq!(result.to_string(), "XCG00GFXXMR3");
+    }
+
+    #[test]
+    fn lenient_still_rejects_invalid() {
+        let invalid_figis = vec![
+            "bsg000blnnh6", // Forbidden start
+            "bbx000blnnh6", // Third character is not 'G'
+            "bbg0a0blnnh6", // ID section contains a vowel
+            "bbg000blnnhh", // Non-numeric check digit
+        ];
+
+        for input in invalid_figis {
+            let result = Figi::from_str_lenient(input);
+            assert!(
+                result.is_err(),
+                "Should fail under lenient parsing: {}",
+                input
+            );
+        }
+        assert!(Figi::from_str("bbg000blnnh6").is_err());
+    }
+
+    #[test]
+    fn deref_to_str() {
+        let figi = Figi::from_str("BBG000BLNNH6").unwrap();
+        assert!(figi.starts_with("BBG"));
+        assert_eq!(figi.len(), 12);
+        assert_eq!(figi.as_ref(), "BBG000BLNNH6");
+    }
+
+    #[test]
+    fn certified_provider_bloomberg() {
+        let figi = Figi::from_str("BBG000BLNNH6").unwrap();
+        assert_eq!(figi.certified_provider(), CertifiedProvider::Bloomberg);
+    }
+
+    #[test]
+    fn certified_provider_other() {
+        let figi = Figi::from_str("XCG00GFXXMR3").unwrap();
+        assert_eq!(figi.certified_provider(), CertifiedProvider::Other(*b"XC"));
+
+        let figi = Figi::from_str_lenient("xyg000psjnq9").unwrap();
+        assert_eq!(figi.certified_provider(), CertifiedProvider::Other(*b"XY"));
+    }
+
+    #[test]
+    fn figi_combinator_leaves_remaining_input() {
+        let mut input = "BBG000BLNNH6 US Equity";
+        let result = figi(&mut input).unwrap();
+        assert_eq!(result.to_string(), "BBG000BLNNH6");
+        assert_eq!(input, " US Equity");
+
+        let mut input = "BBG0A0BLNNH6";
+        assert!(figi(&mut input).is_err());
+        assert_eq!(input, "BBG0A0BLNNH6");
+    }
+
+    #[test]
+    fn reserved_prefixes_starting_with_g() {
+        for input in ["GGG000BLNNH6", "GBG000BLNNH6", "GHG000BLNNH6"] {
+            assert!(
+                Figi::from_str(input).is_err(),
+                "Should fail due to reserved prefix: {}",
+                input
+            );
+            assert!(Figi::from_str_lenient(input).is_err());
+        }
+    }
+
+    #[test]
+    fn display_matches_input() {
+        let figi = Figi::from_str("BBG000BLNNH6").unwrap();
+        assert_eq!(format!("{figi}"), "BBG000BLNNH6");
+        assert_eq!(format!("[{figi}]"), "[BBG000BLNNH6]");
+    }
+
+    #[test]
+    fn verbose_reports_every_position() {
+        let result = parse_figi_verbose("BBX000BLANH6");
+        assert_eq!(
+            result,
+            Err(vec![
+                FigiDiagnostic::Position {
+                    position: 3,
+                    expected: "'G'"
+                },
+                FigiDiagnostic::Position {
+                    position: 9,
+                    expected: "Consonant or numeric character"
+                },
+            ])
+        );
+
+        let result = parse_figi_verbose("BSG000BLNNH").unwrap_err();
+        assert!(result.contains(&FigiDiagnostic::Length(11)));
+        assert!(result.contains(&FigiDiagnostic::Position {
+            position: 1,
+            expected: "Prefix other than BS, BM, GG, GB, GH, KY or VG"
+        }));
+
+        assert_eq!(
+            parse_figi_verbose("BBG000BLNNH6"),
+            Figi::from_str("BBG000BLNNH6").map_err(|_| vec![])
+        );
+    }
+
+    #[test]
+    fn verbose_reports_in_position_order() {
+        let positions = |s| {
+            parse_figi_verbose(s)
+                .unwrap_err()
+                .into_iter()
+                .filter_map(|d| match d {
+                    FigiDiagnostic::Position { position, .. } => Some(position),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        };
+        // A reserved prefix is reported at position 1, before later positions
+        assert_eq!(positions("BSX000BLNNHH"), [1, 3, 12]);
+        assert_eq!(positions("1BX00\u{412}BLNNH6"), [1, 3, 6]);
+        assert_eq!(
+            parse_figi_verbose("BSX000BLNNH6").unwrap_err(),
+            [
+                FigiDiagnostic::Position {
+                    position: 1,
+                    expected: "Prefix other than BS, BM, GG, GB, GH, KY or VG"
+                },
+                FigiDiagnostic::Position {
+                    position: 3,
+                    expected: "'G'"
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn lenient_strips_bom_and_quotes() {
+        for input in [
+            "\u{FEFF}BBG000BLNNH6",
+            "\"BBG000BLNNH6\"",
+            "'BBG000BLNNH6'",
+            "\u{FEFF}\"bbg000blnnh6\"",
+        ] {
+            let result = Figi::from_str_lenient(input).unwrap();
+            assert_eq!(result.to_string(), "BBG000BLNNH6");
+            assert!(
+                Figi::from_str(input).is_err(),
+                "Strict parse must stay exact"
+            );
+        }
+        // Mismatched or doubled quotes are not stripped
+        assert!(Figi::from_str_lenient("\"BBG000BLNNH6'").is_err());
+        assert!(Figi::from_str_lenient("\"\"BBG000BLNNH6\"\"").is_err());
+    }
+
+    #[test]
+    fn normalize_borrows_canonical_input() {
+        assert!(matches!(
+            normalize("BBG000BLNNH6"),
+            Cow::Borrowed("BBG000BLNNH6")
+        ));
+        assert!(matches!(
+            normalize(" \"BBG000BLNNH6\" "),
+            Cow::Borrowed("BBG000BLNNH6")
+        ));
+        let lower = normalize("bbg000blnnh6");
+        assert!(matches!(lower, Cow::Owned(_)));
+        assert_eq!(lower, "BBG000BLNNH6");
+        assert_eq!(
+            Figi::from_str_lenient("  bbg000blnnh6\t")
+                .unwrap()
+                .to_string(),
+            "BBG000BLNNH6"
+        );
+    }
+
+    #[test]
+    fn figi_lenient_combinator() {
+        let mut input = "bbg000blnnh6 us equity";
+        let result = figi_lenient(&mut input).unwrap();
+        assert_eq!(result, Figi::from_str("BBG000BLNNH6").unwrap());
+        assert_eq!(input, " us equity");
+    }
+
+    #[test]
+    fn validate_bytes_checks_every_rule() {
+        assert_eq!(validate_bytes(b"BBG000BLNNH6"), Ok(()));
+        // A valid FIGI inside a larger buffer, as when scanning mapped memory
+        let buf = b"xxBBG000BLNNH6xx";
+        assert_eq!(validate_bytes(&buf[2..14]), Ok(()));
+
+        assert!(matches!(
+            validate_bytes(b"BBG000BLNNH7"),
+            Err(InvalidFigi::Checksum(_))
+        ));
+        assert!(matches!(
+            validate_bytes(b"BBG000BLNNH"),
+            Err(InvalidFigi::Length(_))
+        ));
+        assert!(matches!(
+            validate_bytes(&buf[..]),
+            Err(InvalidFigi::Length(_))
+        ));
+        assert!(matches!(validate_bytes(b""), Err(InvalidFigi::Length(_))));
+        assert!(matches!(
+            validate_bytes(b"BBG000BLNN\xC96"),
+            Err(InvalidFigi::ConsonantOrNumeric(_))
+        ));
+        assert!(matches!(
+            validate_bytes(b"\xC9BG000BLNNH6"),
+            Err(InvalidFigi::Consonant(_))
+        ));
+    }
+
+    #[test]
+    fn generic_prefix_agrees_with_bbg_fast_path() {
+        let corpus = [
+            "BBG000BLNNH6",
+            "BBG000B9XRY4 trailing",
+            "BBG00",
+            "BBX000BLNNH6",
+            "BCG000BLNNH6",
+            "KKG000000005",
+            "XCG00GFXXMR5",
+            "GGG000BLNNH6",
+            "BSG000BLNNH6",
+            "VGG000BLNNH6",
+            "AAG000BLNNH6",
+            "bbg000blnnh6",
+            "",
+        ];
+        for input in corpus {
+            let fast = figi.parse_peek(input);
+            let generic = parse_figi_generic.parse_peek(input);
+            assert_eq!(fast.is_ok(), generic.is_ok(), "{input}");
+            if let (Ok(fast), Ok(generic)) = (fast, generic) {
+                assert_eq!(fast, generic, "{input}");
+            }
+        }
+    }
+
+    #[test]
+    fn figi_in_context_tagged() {
+        let mut input = "FIGI=BBG000N88V36;TICKER=AAPL";
+        let (offset, found) = figi_in_context(&mut input).unwrap();
+        assert_eq!(offset, 5);
+        assert_eq!(&*found, "BBG000N88V36");
+        assert_eq!(input, ";TICKER=AAPL");
+
+        let mut input = "TICKER=AAPL";
+        assert!(figi_in_context(&mut input).is_err());
+    }
+
+    #[test]
+    fn figi_in_context_respects_word_boundaries() {
+        // FIGI-shaped runs inside longer alphanumeric tokens are skipped
+        let mut input = "XBBG000N88V36 BBG000N88V367 BBG000BLNNH6";
+        let (offset, found) = figi_in_context(&mut input).unwrap();
+        assert_eq!(offset, 28);
+        assert_eq!(&*found, "BBG000BLNNH6");
+    }
+
+    #[test]
+    fn figis_in_context_finds_all() {
+        let s = "é FIGI=BBG000N88V36;ALT=BBG000BLNNH6;";
+        let found = figis_in_context(s);
+        let found: Vec<(usize, &str)> = found.iter().map(|(i, f)| (*i, &**f)).collect();
+        assert_eq!(found, [(8, "BBG000N88V36"), (25, "BBG000BLNNH6")]);
+        assert_eq!(&s[8..20], "BBG000N88V36");
+        assert!(figis_in_context("no figis here").is_empty());
+    }
+
+    #[test]
+    fn lenient_and_strict_parses_are_equal() {
+        let strict = Figi::from_str("BBG000BLNNH6").unwrap();
+        for input in ["bbg000blnnh6", "BbG000bLnNh6", " \"bbg000blnnh6\" "] {
+            let lenient = Figi::from_str_lenient(input).unwrap();
+            assert_eq!(lenient, strict, "{input}");
+            assert_eq!(lenient.0, "BBG000BLNNH6");
+        }
+        assert_eq!(figi_lenient.parse("bbg000blnnh6").unwrap(), strict);
+
+        assert!(strict.eq_ignore_ascii_case("bbg000blnnh6"));
+        assert!(!strict.eq_ignore_ascii_case("bbg000blnnh7"));
+    }
+
+    #[test]
+    fn test_compact_figi() {
+        for s in ["BBG000BLNNH6", "XCG00GFXXMR5", "BZG000000004"] {
+            let figi = Figi::from_str(s).unwrap();
+            let compact = CompactFigi::from(&figi);
+            assert_eq!(compact.full(), s);
+            assert_eq!(compact.to_string(), s);
+            assert_eq!(compact.certified_provider(), figi.certified_provider());
+            assert_eq!(Figi::from(compact), figi);
+        }
+    }
+
+    #[test]
+    fn test_compact_figi_size() {
+        // Naive storage is a 12-character String: the String itself plus 12
+        // bytes on the heap
+        let naive = std::mem::size_of::<String>() + 12;
+        assert_eq!(std::mem::size_of::<CompactFigi>(), 12);
+        assert!(std::mem::size_of::<CompactFigi>() < naive);
+        assert!(std::mem::size_of::<CompactFigi>() < std::mem::size_of::<Figi>());
+    }
+
+    #[test]
+    fn test_parse_qualified() {
+        let aapl = Figi::from_str("BBG000N88V36").unwrap();
+        let q = Figi::parse_qualified("BBG000N88V36@UN").unwrap();
+        assert_eq!(q.figi, aapl);
+        assert_eq!(q.exchange.as_deref(), Some("UN"));
+        let q = Figi::parse_qualified("BBG000N88V36@X2").unwrap();
+        assert_eq!(q.exchange.as_deref(), Some("X2"));
+
+        let q = Figi::parse_qualified("BBG000N88V36").unwrap();
+        assert_eq!(
+            q,
+            QualifiedFigi {
+                figi: aapl,
+                exchange: None
+            }
+        );
+
+        for s in [
+            "BBG000N88V36@",
+            "BBG000N88V36@un",
+            "BBG000N88V36@U N",
+            "BBG000N88V36@UN@US",
+            "BBG000N88V3@UN",
+            "BSG000N88V36@UN",
+            "@UN",
+        ] {
+            assert!(Figi::parse_qualified(s).is_err(), "{s}");
+        }
+    }
+
+    #[test]
+    fn test_prefix3() {
+        let figi = Figi::from_str("BBG000BLNNH6").unwrap();
+        assert_eq!(figi.prefix3(), "BBG");
+        let figi = Figi::from_str_lenient("xcg00gfxxmr5").unwrap();
+        assert_eq!(figi.prefix3(), "XCG");
+        let ibrk: crate::ibrk_figi::Figi = "XCG00GFXXMR5".parse().unwrap();
+        assert!(figi.prefix3().chars().eq(ibrk.prefix3()));
+    }
+
+    #[test]
+    fn test_try_from_string() {
+        let s = String::from("BBG000BLNNH6");
+        let ptr = s.as_ptr();
+        let figi = Figi::try_from(s).unwrap();
+        assert_eq!(figi, Figi::from_str("BBG000BLNNH6").unwrap());
+        // The buffer was moved in, not copied
+        assert_eq!(figi.0.as_ptr(), ptr);
+
+        assert!(Figi::try_from(String::from("BSG000BLNNH6")).is_err());
+        assert!(Figi::try_from(String::from("BBG000BLNNH6 ")).is_err());
+    }
 }
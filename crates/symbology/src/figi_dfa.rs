@@ -0,0 +1,194 @@
+//! A byte-at-a-time FIGI validator for streams and constrained targets.
+//!
+//! [`FigiDfa`] holds a few bytes of state, uses only `core`, and never
+//! allocates. It accepts exactly the codes [`crate::ibrk_figi::Figi`] accepts,
+//! including the check digit, but reports only accept or reject.
+
+use crate::ibrk_figi::{
+    check_digit_from_sum, check_digit_term, check_digit_value, is_reserved_prefix, CONSONANTS,
+    FIGI_ALPHABET,
+};
+
+/// Marks bytes that are not a consonant or digit in [`VALUE`].
+const INVALID: u8 = 0xFF;
+
+/// The [`check_digit_value`] of each byte in [`FIGI_ALPHABET`], and
+/// [`INVALID`] for everything else.
+const VALUE: [u8; 256] = {
+    let mut table = [INVALID; 256];
+    let mut i = 0;
+    while i < FIGI_ALPHABET.len() {
+        let c = FIGI_ALPHABET[i];
+        table[c as usize] = check_digit_value(c);
+        i += 1;
+    }
+    table
+};
+
+/// Whether each byte is one of the [`CONSONANTS`].
+const CONSONANT: [bool; 256] = {
+    let mut table = [false; 256];
+    let mut i = 0;
+    while i < CONSONANTS.len() {
+        table[CONSONANTS[i] as usize] = true;
+        i += 1;
+    }
+    table
+};
+
+/// What [`FigiDfa::step`] concluded after a byte.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DfaResult {
+    /// Valid so far; more bytes are needed.
+    Continue,
+    /// The 12 bytes so far are a valid FIGI.
+    Accept,
+    /// The bytes cannot be a valid FIGI, whatever follows.
+    Reject,
+}
+
+/// A deterministic automaton over FIGI bytes, with the check digit sum
+/// accumulated as it goes.
+///
+/// # Example
+/// ```
+/// use symbology::figi_dfa::{DfaResult, FigiDfa};
+///
+/// let mut dfa = FigiDfa::new();
+/// let results: Vec<DfaResult> = b"BBG000N88V36".iter().map(|b| dfa.step(*b)).collect();
+/// assert_eq!(results[..11], [DfaResult::Continue; 11]);
+/// assert_eq!(results[11], DfaResult::Accept);
+/// ```
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct FigiDfa {
+    /// Bytes consumed, or `u8::MAX` once rejected.
+    position: u8,
+    /// The first byte, kept to check for a reserved prefix.
+    first: u8,
+    /// The check digit sum so far, mod 10.
+    sum: u8,
+}
+
+impl FigiDfa {
+    /// A fresh automaton expecting the first byte.
+    pub const fn new() -> Self {
+        FigiDfa {
+            position: 0,
+            first: 0,
+            sum: 0,
+        }
+    }
+
+    /// Start over, as if no bytes had been fed.
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Feed the next byte. A 13th byte, or any byte after a rejection, is
+    /// rejected.
+    pub fn step(&mut self, byte: u8) -> DfaResult {
+        let value = VALUE[byte as usize];
+        let consonant = CONSONANT[byte as usize];
+        let ok = match self.position {
+            0 => consonant,
+            1 => consonant && !is_reserved_prefix(char::from(self.first), char::from(byte)),
+            2 => byte == b'G',
+            3..=10 => value != INVALID,
+            11 => {
+                self.position = u8::MAX;
+                return if value == check_digit_from_sum(self.sum) {
+                    DfaResult::Accept
+                } else {
+                    DfaResult::Reject
+                };
+            }
+            _ => false,
+        };
+        if !ok {
+            self.position = u8::MAX;
+            return DfaResult::Reject;
+        }
+        let term = check_digit_term(value, usize::from(self.position) + 1);
+        self.sum = (self.sum + term) % 10;
+        if self.position == 0 {
+            self.first = byte;
+        }
+        self.position += 1;
+        DfaResult::Continue
+    }
+
+    /// Run a fresh automaton over `bytes`, accepting only if they are exactly
+    /// one valid FIGI.
+    pub fn validate(bytes: &[u8]) -> bool {
+        let mut dfa = Self::new();
+        let mut result = DfaResult::Continue;
+        for &b in bytes {
+            result = dfa.step(b);
+            if result == DfaResult::Reject {
+                return false;
+            }
+        }
+        result == DfaResult::Accept
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_sequence() {
+        let mut dfa = FigiDfa::new();
+        for &b in &b"BBG000BLNNH6"[..11] {
+            assert_eq!(dfa.step(b), DfaResult::Continue);
+        }
+        assert_eq!(dfa.step(b'6'), DfaResult::Accept);
+        // Anything after a complete FIGI is rejected
+        assert_eq!(dfa.step(b'0'), DfaResult::Reject);
+
+        dfa.reset();
+        assert!(b"KKG000000007"
+            .iter()
+            .all(|&b| dfa.step(b) != DfaResult::Reject));
+    }
+
+    #[test]
+    fn test_rejects_as_soon_as_possible() {
+        let reject_at = |bytes: &[u8]| {
+            let mut dfa = FigiDfa::new();
+            bytes.iter().position(|&b| dfa.step(b) == DfaResult::Reject)
+        };
+        assert_eq!(reject_at(b"ABG000BLNNH6"), Some(0));
+        assert_eq!(reject_at(b"BSG000BLNNH6"), Some(1));
+        assert_eq!(reject_at(b"BBX000BLNNH6"), Some(2));
+        assert_eq!(reject_at(b"BBG00a0BLNNH"), Some(5));
+        assert_eq!(reject_at(b"BBG000BLNNH7"), Some(11));
+        assert_eq!(reject_at(b"BBG000BLNNHH"), Some(11));
+        assert_eq!(reject_at(b"BBG000BLNNH\xFF"), Some(11));
+        assert_eq!(reject_at(b"BBG000BLNNH6"), None);
+    }
+
+    #[test]
+    fn test_validate_requires_exactly_one_figi() {
+        assert!(FigiDfa::validate(b"BBG000BLNNH6"));
+        assert!(!FigiDfa::validate(b"BBG000BLNNH"));
+        assert!(!FigiDfa::validate(b"BBG000BLNNH60"));
+        assert!(!FigiDfa::validate(b""));
+    }
+
+    #[test]
+    fn test_agrees_with_ibrk_figi_on_vectors() {
+        let vectors = include_str!("../tests/data/figi_vectors.txt");
+        for line in vectors
+            .lines()
+            .filter(|l| !l.is_empty() && !l.starts_with('#'))
+        {
+            let (input, _) = line.split_once(' ').unwrap();
+            assert_eq!(
+                FigiDfa::validate(input.as_bytes()),
+                input.parse::<crate::ibrk_figi::Figi>().is_ok(),
+                "{input}"
+            );
+        }
+    }
+}
@@ -0,0 +1,181 @@
+//! Relationships between FIGIs for the same instrument.
+//!
+//! OpenFIGI assigns an instrument a composite FIGI, one FIGI per exchange it
+//! trades on, and a share-class FIGI spanning all of them. The crate cannot
+//! discover these offline; consumers build groups from API data and use
+//! [`FigiGroup::validate`] to check them.
+
+use std::collections::HashMap;
+
+use crate::ibrk_figi::Figi;
+
+/// A consumer-supplied mapping from a FIGI to its share-class FIGI.
+pub type ShareClassMap = HashMap<Figi, Figi>;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// The FIGIs related to one instrument.
+pub struct FigiGroup {
+    /// The composite FIGI, which stands for the instrument across every
+    /// exchange in one country.
+    pub composite: Figi,
+    /// One FIGI per exchange the instrument trades on, each rolling up to
+    /// [`FigiGroup::composite`].
+    pub exchange_level: Vec<Figi>,
+    /// The share-class FIGI, which spans every composite of the share class
+    /// worldwide, if known.
+    pub share_class: Option<Figi>,
+}
+
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+/// The ways a [`FigiGroup`] can be internally inconsistent
+pub enum InvalidFigiGroup {
+    /// The composite FIGI is also listed as an exchange-level FIGI.
+    CompositeIsExchangeLevel(Figi),
+    /// The same exchange-level FIGI is listed more than once.
+    DuplicateExchangeLevel(Figi),
+    /// The share-class FIGI is also the composite or an exchange-level FIGI.
+    ShareClassReused(Figi),
+}
+
+impl std::fmt::Display for InvalidFigiGroup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        let msg = match self {
+            Self::CompositeIsExchangeLevel(figi) => format!(
+                "Composite FIGI {} is also an exchange-level FIGI",
+                String::from(figi)
+            ),
+            Self::DuplicateExchangeLevel(figi) => format!(
+                "Exchange-level FIGI {} is listed more than once",
+                String::from(figi)
+            ),
+            Self::ShareClassReused(figi) => format!(
+                "Share-class FIGI {} is also a composite or exchange-level FIGI",
+                String::from(figi)
+            ),
+        };
+        write!(f, "Invalid FIGI group. {}", &msg)
+    }
+}
+
+impl std::error::Error for InvalidFigiGroup {}
+
+impl FigiGroup {
+    /// Check that every FIGI in the group plays exactly one role.
+    ///
+    /// # Errors
+    /// Will error on the first FIGI found in more than one role, or listed
+    /// twice as exchange-level.
+    pub fn validate(&self) -> Result<(), InvalidFigiGroup> {
+        for (i, figi) in self.exchange_level.iter().enumerate() {
+            if *figi == self.composite {
+                return Err(InvalidFigiGroup::CompositeIsExchangeLevel(*figi));
+            }
+            if self.exchange_level[..i].contains(figi) {
+                return Err(InvalidFigiGroup::DuplicateExchangeLevel(*figi));
+            }
+        }
+        if let Some(share_class) = self.share_class {
+            if share_class == self.composite || self.exchange_level.contains(&share_class) {
+                return Err(InvalidFigiGroup::ShareClassReused(share_class));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Whether `a` and `b` roll up to the same share class according to `links`.
+///
+/// A FIGI missing from `links` is treated as its own share class, so a
+/// share-class FIGI matches the listings that link to it, but two unlinked
+/// FIGIs only match if they are equal.
+pub fn same_share_class(a: &Figi, b: &Figi, links: &ShareClassMap) -> bool {
+    links.get(a).unwrap_or(a) == links.get(b).unwrap_or(b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn figi(s: &str) -> Figi {
+        s.parse().unwrap()
+    }
+
+    // A composite, two exchange-level listings and a share class
+    fn aapl() -> FigiGroup {
+        FigiGroup {
+            composite: figi("BBG000B9XRY4"),
+            exchange_level: vec![figi("BBG000B9Y5X2"), figi("BBG000B9XVV8")],
+            share_class: Some(figi("BBG001S5N8V8")),
+        }
+    }
+
+    #[test]
+    fn test_valid_group() {
+        assert_eq!(aapl().validate(), Ok(()));
+        let group = FigiGroup {
+            share_class: None,
+            exchange_level: vec![],
+            ..aapl()
+        };
+        assert_eq!(group.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_inconsistent_groups() {
+        let mut group = aapl();
+        group.exchange_level.push(group.composite);
+        assert_eq!(
+            group.validate(),
+            Err(InvalidFigiGroup::CompositeIsExchangeLevel(group.composite))
+        );
+
+        let mut group = aapl();
+        group.exchange_level.push(group.exchange_level[0]);
+        assert_eq!(
+            group.validate(),
+            Err(InvalidFigiGroup::DuplicateExchangeLevel(
+                group.exchange_level[0]
+            ))
+        );
+
+        let mut group = aapl();
+        group.share_class = Some(group.exchange_level[1]);
+        assert_eq!(
+            group.validate(),
+            Err(InvalidFigiGroup::ShareClassReused(group.exchange_level[1]))
+        );
+    }
+
+    #[test]
+    fn test_same_share_class() {
+        let group = aapl();
+        let share_class = group.share_class.unwrap();
+        let mut links: ShareClassMap = group
+            .exchange_level
+            .iter()
+            .chain([&group.composite])
+            .map(|f| (*f, share_class))
+            .collect();
+        let msft = figi("BBG000BPH459");
+        let msft_share_class = figi("BBG001S5TD05");
+        links.insert(msft, msft_share_class);
+
+        assert!(same_share_class(
+            &group.composite,
+            &group.exchange_level[0],
+            &links
+        ));
+        assert!(same_share_class(
+            &group.exchange_level[1],
+            &share_class,
+            &links
+        ));
+        assert!(!same_share_class(&group.composite, &msft, &links));
+        assert!(same_share_class(&msft, &msft_share_class, &links));
+
+        let unlinked = figi("BBG000BLNNH6");
+        assert!(same_share_class(&unlinked, &unlinked, &links));
+        assert!(!same_share_class(&unlinked, &group.composite, &links));
+    }
+}
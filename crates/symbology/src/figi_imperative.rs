@@ -1,18 +1,73 @@
 use std::str::FromStr;
 
-#[derive(Debug, PartialEq)]
+use crate::ibrk_figi::{is_reserved_prefix, InvalidFigi};
+
+#[derive(Debug, Clone, PartialEq)]
 pub struct Figi(pub String);
 
-#[derive(Debug)]
+/// Why a string is not a FIGI. Every variant carries the rejected input;
+/// positions are 1-based character positions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum FigiParseError {
-    // 12 chars only
-    InvalidLength,
-    // Consonants/digits or 'G' at pos 3
-    InvalidFormat,
-    // BS, BM, GG, GB, GH, KY, VG
-    InvalidComponent,
-    // Checksum integrity
-    InvalidChecksum,
+    /// The input is not exactly 12 bytes.
+    InvalidLength(String),
+    /// The character at `position` is not a consonant or digit.
+    InvalidFormat {
+        /// The rejected input.
+        input: String,
+        /// The 1-based character position, as in
+        /// [`crate::figi::FigiDiagnostic::Position`].
+        position: usize,
+    },
+    /// A reserved prefix (BS, BM, GG, GB, GH, KY, VG) at position 1, or a
+    /// character other than `G` at position 3.
+    InvalidComponent {
+        /// The rejected input.
+        input: String,
+        /// 1 for a reserved prefix or 3 for the `G`, 1-based as in
+        /// [`crate::figi::FigiDiagnostic::Position`].
+        position: usize,
+    },
+    /// The check digit is not a digit. The checksum itself is not verified.
+    InvalidChecksum(String),
+}
+
+impl std::fmt::Display for FigiParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        let msg = match self {
+            Self::InvalidLength(s) => format!("Invalid length. A FIGI code is exactly 12 characters long. {s}"),
+            Self::InvalidFormat { input, position } => format!("Invalid character at position {position} of {input}. Only consonants and digits are allowed."),
+            Self::InvalidComponent { input, position: 1 } => format!("Invalid first two characters for {input}. First two characters cannot be BS, BM, GG, GB, GH, KY, or VG."),
+            Self::InvalidComponent { input, position } => format!("Invalid character at position {position} of {input}. Third character must be G"),
+            Self::InvalidChecksum(s) => format!("Invalid check digit for {s}. The check digit must be a digit."),
+        };
+        write!(f, "Invalid FIGI. {}", &msg)
+    }
+}
+
+impl std::error::Error for FigiParseError {}
+
+/// Migration path onto [`InvalidFigi`]. The input is kept, but the mapping
+/// is still lossy:
+///
+/// - `InvalidFormat` only means "some character was not a consonant or digit",
+///   which is reported as `ConsonantOrNumeric` even if the bad character was
+///   in the prefix.
+/// - `InvalidComponent` is reported as `FirstTwo` for the prefix and `Third`
+///   for position 3.
+/// - `InvalidChecksum` is raised for a non-numeric check digit and is reported
+///   as `Checksum`.
+impl From<FigiParseError> for InvalidFigi {
+    fn from(value: FigiParseError) -> Self {
+        match value {
+            FigiParseError::InvalidLength(s) => InvalidFigi::Length(s),
+            FigiParseError::InvalidFormat { input, .. } => InvalidFigi::ConsonantOrNumeric(input),
+            FigiParseError::InvalidComponent { input, position: 1 } => InvalidFigi::FirstTwo(input),
+            FigiParseError::InvalidComponent { input, .. } => InvalidFigi::Third(input),
+            FigiParseError::InvalidChecksum(s) => InvalidFigi::Checksum(s),
+        }
+    }
 }
 
 impl FromStr for Figi {
@@ -22,24 +77,34 @@ impl FromStr for Figi {
         let valid_chars = "0123456789BCDFGHJKLMNPQRSTVWXYZ";
         // Length must be 12
         if s.len() != 12 {
-            return Err(FigiParseError::InvalidLength);
+            return Err(FigiParseError::InvalidLength(s.to_owned()));
         }
         // Each character must be valid
-        if !s.chars().all(|c| valid_chars.contains(c)) {
-            return Err(FigiParseError::InvalidFormat);
+        if let Some(i) = s.chars().position(|c| !valid_chars.contains(c)) {
+            return Err(FigiParseError::InvalidFormat {
+                input: s.to_owned(),
+                position: i + 1,
+            });
         }
-        // Restricted prefixes
-        match &s[0..2] {
-            "BS" | "BM" | "GG" | "GB" | "VG" => return Err(FigiParseError::InvalidComponent),
-            _ => {}
+        // Every character is now ASCII, so indexing bytes cannot split one
+        let b = s.as_bytes();
+        // Restricted prefixes, checked before the third character
+        if is_reserved_prefix(char::from(b[0]), char::from(b[1])) {
+            return Err(FigiParseError::InvalidComponent {
+                input: s.to_owned(),
+                position: 1,
+            });
         }
         // Third character must be 'G'
-        if &s[2..3] != "G" {
-            return Err(FigiParseError::InvalidComponent);
+        if b[2] != b'G' {
+            return Err(FigiParseError::InvalidComponent {
+                input: s.to_owned(),
+                position: 3,
+            });
         }
         // Last character must be a digit and ignore checksum for now
-        if !s.chars().last().unwrap().is_digit(10) {
-            return Err(FigiParseError::InvalidChecksum);
+        if !b[11].is_ascii_digit() {
+            return Err(FigiParseError::InvalidChecksum(s.to_owned()));
         }
         Ok(Self(s.to_string()))
     }
@@ -60,43 +125,131 @@ mod tests {
         let figi_str = "BBG00B9XVV8"; // Missing one character
         assert!(matches!(
             Figi::from_str(figi_str),
-            Err(FigiParseError::InvalidLength)
+            Err(FigiParseError::InvalidLength(_))
         ));
     }
 
     #[test]
     fn test_invalid_characters() {
         let figi_str = "BBG00B9XV?V8"; // Contains an invalid character
-        assert!(matches!(
+        assert_eq!(
             Figi::from_str(figi_str),
-            Err(FigiParseError::InvalidFormat)
-        ));
+            Err(FigiParseError::InvalidFormat {
+                input: figi_str.to_owned(),
+                position: 10
+            })
+        );
     }
 
     #[test]
     fn test_invalid_prefix() {
         let figi_str = "BSG000B9XVV8"; // Invalid prefix
-        assert!(matches!(
+        assert_eq!(
             Figi::from_str(figi_str),
-            Err(FigiParseError::InvalidComponent)
-        ));
+            Err(FigiParseError::InvalidComponent {
+                input: figi_str.to_owned(),
+                position: 1
+            })
+        );
     }
 
     #[test]
     fn test_missing_g_in_third_place() {
         let figi_str = "BBB000B9XVV8"; // 'G' missing at position 3
-        assert!(matches!(
+        assert_eq!(
             Figi::from_str(figi_str),
-            Err(FigiParseError::InvalidComponent)
-        ));
+            Err(FigiParseError::InvalidComponent {
+                input: figi_str.to_owned(),
+                position: 3
+            })
+        );
     }
 
     #[test]
     fn test_invalid_checksum_digit() {
         let figi_str = "BBG000B9XVVX"; // Last character is not a digit
-        assert!(matches!(
+        assert_eq!(
             Figi::from_str(figi_str),
-            Err(FigiParseError::InvalidChecksum)
-        ));
+            Err(FigiParseError::InvalidChecksum(figi_str.to_owned()))
+        );
+    }
+
+    #[test]
+    fn test_into_invalid_figi() {
+        let cases = [
+            ("BBG00B9XVV8", InvalidFigi::Length("BBG00B9XVV8".to_owned())),
+            (
+                "BBG00B9XV?V8",
+                InvalidFigi::ConsonantOrNumeric("BBG00B9XV?V8".to_owned()),
+            ),
+            (
+                "BSG000B9XVV8",
+                InvalidFigi::FirstTwo("BSG000B9XVV8".to_owned()),
+            ),
+            (
+                "BBB000B9XVV8",
+                InvalidFigi::Third("BBB000B9XVV8".to_owned()),
+            ),
+            (
+                "BBG000B9XVVX",
+                InvalidFigi::Checksum("BBG000B9XVVX".to_owned()),
+            ),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(
+                InvalidFigi::from(Figi::from_str(input).unwrap_err()),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_reserved_prefixes() {
+        for figi_str in [
+            "BSG000B9XVV8",
+            "BMG000B9XVV8",
+            "GGG000B9XVV8",
+            "GBG000B9XVV8",
+            "GHG000B9XVV8",
+            "KYG000B9XVV8",
+            "VGG000B9XVV8",
+        ] {
+            assert!(
+                matches!(
+                    Figi::from_str(figi_str),
+                    Err(FigiParseError::InvalidComponent { position: 1, .. })
+                ),
+                "{figi_str}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_non_ascii_input() {
+        // 12 bytes, but `É` and `Б` are two bytes each, so byte offsets 1
+        // and 2 fall inside a character
+        for figi_str in ["ÉBG000B9XVV", "BБG000B9XV8"] {
+            assert_eq!(figi_str.len(), 12);
+            assert!(matches!(
+                Figi::from_str(figi_str),
+                Err(FigiParseError::InvalidFormat { .. })
+            ));
+        }
+        assert_eq!(
+            Figi::from_str("BBG000B9XVÉ"),
+            Err(FigiParseError::InvalidFormat {
+                input: "BBG000B9XVÉ".to_owned(),
+                position: 11
+            })
+        );
+    }
+
+    #[test]
+    fn test_error_display_includes_input() {
+        let err = Figi::from_str("BBB000B9XVV8").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid FIGI. Invalid character at position 3 of BBB000B9XVV8. Third character must be G"
+        );
     }
 }
@@ -241,9 +241,13 @@ impl From<G> for u8 {
     }
 }
 
-#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
 #[allow(clippy::struct_field_names)]
 /// A valid FIGI code. See the module level documentation for a link to the official standard.
+///
+/// `Ord` agrees with byte-wise ordering of the FIGI strings, because the enum
+/// discriminants follow ASCII order, so a sorted `Vec<Figi>` can be searched
+/// with [`slice::binary_search`].
 pub struct Figi {
     pos_1: Consonant,
     pos_2: Consonant,
@@ -251,21 +255,85 @@ pub struct Figi {
     pos_4_12: [ConsonantOrNumeric; 9],
 }
 
+/// The canonical 12 characters. The alternate flag, `{:#}`, groups them 3-5-4
+/// with hyphens for reading: provider prefix and `G`, then two runs of the
+/// body, the last ending in the check digit.
+///
+/// ```
+/// use symbology::ibrk_figi::Figi;
+///
+/// let figi: Figi = "BBG000N88V36".parse().unwrap();
+/// assert_eq!(format!("{figi}"), "BBG000N88V36");
+/// assert_eq!(format!("{figi:#}"), "BBG-000N8-8V36");
+/// ```
+impl std::fmt::Display for Figi {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let plain = self.to_ascii_bytes();
+        let mut grouped = [b'-'; 14];
+        let bytes: &[u8] = if f.alternate() {
+            grouped[..3].copy_from_slice(&plain[..3]);
+            grouped[4..9].copy_from_slice(&plain[3..8]);
+            grouped[10..].copy_from_slice(&plain[8..]);
+            &grouped
+        } else {
+            &plain
+        };
+        // Every FIGI character is ASCII, so this never fails
+        f.pad(std::str::from_utf8(bytes).map_err(|_| std::fmt::Error)?)
+    }
+}
+
+/// Prints the FIGI string, `Figi("BBG000N88V36")`, or with the
+/// `redact-debug` feature the [`Figi::masked`] form, `Figi("BBG*******36")`.
+impl std::fmt::Debug for Figi {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = if cfg!(feature = "redact-debug") {
+            self.masked()
+        } else {
+            String::from(self)
+        };
+        f.debug_tuple("Figi").field(&s).finish()
+    }
+}
+
+/// Hashes the [`PackedFigi`] as a single `u64` rather than 12 separate fields.
+/// Packing is injective, so this agrees with `Eq`.
+impl std::hash::Hash for Figi {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        u64::from_be_bytes(self.pack().0).hash(state);
+    }
+}
+
 impl std::str::FromStr for Figi {
     type Err = InvalidFigi;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let b: [u8; 12] = s
-            .as_bytes()
-            .try_into()
-            .map_err(|_| InvalidFigi::Length(s.to_owned()))?;
-        let s = b.map(|c| c as char);
+        if !s.is_ascii() {
+            let confusable = s
+                .chars()
+                .enumerate()
+                .find(|(_, c)| confusable_for(*c).is_some());
+            if let Some((i, found)) = confusable {
+                return Err(trace_invalid(InvalidFigi::Confusable {
+                    input: s.to_owned(),
+                    position: i + 1,
+                    found,
+                }));
+            }
+        }
+        Self::from_bytes(s.as_bytes())
+    }
+}
 
-        Self::from_chars(&s)
+impl TryFrom<[u8; 12]> for Figi {
+    type Error = InvalidFigi;
+
+    fn try_from(value: [u8; 12]) -> Result<Self, Self::Error> {
+        Self::from_bytes(&value)
     }
 }
 
-impl<'a> From<&'a Figi> for String {
+impl From<&Figi> for String {
     fn from(value: &Figi) -> Self {
         let mut s = String::with_capacity(12);
         s.push(value.pos_1.into());
@@ -280,7 +348,27 @@ impl<'a> From<&'a Figi> for String {
 
 #[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
 #[allow(clippy::module_name_repetitions)]
+#[non_exhaustive]
 /// Represents all the possible ways a FIGI code could be invalid
+///
+/// New validation rules add variants, so matches outside this crate need a
+/// wildcard arm.
+///
+/// # Example
+/// ```
+/// use symbology::ibrk_figi::{Figi, InvalidFigi};
+///
+/// assert!("BBG000N88V36".parse::<Figi>().is_ok());
+///
+/// let err = |s: &str| s.parse::<Figi>().unwrap_err();
+/// assert!(matches!(err("BBG000N88V37"), InvalidFigi::Checksum(_)));
+/// assert!(matches!(err("BSG000N88V36"), InvalidFigi::FirstTwo(_)));
+/// assert!(matches!(err("BBX000N88V36"), InvalidFigi::Third(_)));
+/// assert!(matches!(err("BAG000N88V36"), InvalidFigi::Consonant(_)));
+/// assert!(matches!(err("BBG000N88A36"), InvalidFigi::ConsonantOrNumeric(_)));
+/// assert!(matches!(err("BBG000N88V3"), InvalidFigi::Length(_)));
+/// assert!(matches!(err("\u{412}BG000N88V36"), InvalidFigi::Confusable { position: 1, .. }));
+/// ```
 pub enum InvalidFigi {
     /// The checksum is invalid
     Checksum(String),
@@ -294,6 +382,74 @@ pub enum InvalidFigi {
     ConsonantOrNumeric(String),
     /// The provided code is not exactly twelve characters.
     Length(String),
+    /// A non-ASCII character that looks like a valid FIGI character, such as
+    /// Cyrillic `В` for Latin `B`.
+    Confusable {
+        /// The rejected input.
+        input: String,
+        /// The 1-based character position.
+        position: usize,
+        /// The look-alike character.
+        found: char,
+    },
+}
+
+/// The structural rule [`Figi::check_structure`] found broken: the
+/// [`InvalidFigi`] variant to report, before its input is attached.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StructuralError {
+    FirstTwo,
+    Consonant,
+    Third,
+    ConsonantOrNumeric,
+}
+
+impl StructuralError {
+    fn with_input(self, input: String) -> InvalidFigi {
+        match self {
+            StructuralError::FirstTwo => InvalidFigi::FirstTwo(input),
+            StructuralError::Consonant => InvalidFigi::Consonant(input),
+            StructuralError::Third => InvalidFigi::Third(input),
+            StructuralError::ConsonantOrNumeric => InvalidFigi::ConsonantOrNumeric(input),
+        }
+    }
+}
+
+/// Non-ASCII look-alikes of valid FIGI characters: the look-alike, its script,
+/// and the Latin character it imitates. Look-alikes of vowels are left out, as
+/// vowels are not valid FIGI characters either.
+const CONFUSABLES: &[(char, &str, char)] = &[
+    ('\u{412}', "Cyrillic", 'B'),
+    ('\u{421}', "Cyrillic", 'C'),
+    ('\u{41D}', "Cyrillic", 'H'),
+    ('\u{408}', "Cyrillic", 'J'),
+    ('\u{41A}', "Cyrillic", 'K'),
+    ('\u{41C}', "Cyrillic", 'M'),
+    ('\u{420}', "Cyrillic", 'P'),
+    ('\u{405}', "Cyrillic", 'S'),
+    ('\u{422}', "Cyrillic", 'T'),
+    ('\u{425}', "Cyrillic", 'X'),
+    ('\u{4AE}', "Cyrillic", 'Y'),
+    ('\u{417}', "Cyrillic", '3'),
+    ('\u{392}', "Greek", 'B'),
+    ('\u{397}', "Greek", 'H'),
+    ('\u{39A}', "Greek", 'K'),
+    ('\u{39C}', "Greek", 'M'),
+    ('\u{39D}', "Greek", 'N'),
+    ('\u{3A1}', "Greek", 'P'),
+    ('\u{3A4}', "Greek", 'T'),
+    ('\u{3A7}', "Greek", 'X'),
+    ('\u{3A5}', "Greek", 'Y'),
+    ('\u{396}', "Greek", 'Z'),
+];
+
+/// The script and Latin character that `c` imitates, if it is a known
+/// confusable.
+fn confusable_for(c: char) -> Option<(&'static str, char)> {
+    CONFUSABLES
+        .iter()
+        .find(|(look_alike, _, _)| *look_alike == c)
+        .map(|(_, script, latin)| (*script, *latin))
 }
 
 impl std::fmt::Display for InvalidFigi {
@@ -305,6 +461,12 @@ impl std::fmt::Display for InvalidFigi {
             Self::Consonant(s) => format!("Invalid consonant found for {s}. {InvalidConsonant}"),
             Self::ConsonantOrNumeric(s) => format!("Invalid consonant or numeric found for {s}. {InvalidConsonantOrNumeric}"),
             Self::Length(s) => format!("Invalid length. A FIGI code is exactly 12 characters long. {s}"),
+            Self::Confusable { input, position, found } => match confusable_for(*found) {
+                Some((script, latin)) => {
+                    format!("Found {script} {found}, expected Latin {latin} at position {position} of {input}.")
+                }
+                None => format!("Found look-alike character {found} at position {position} of {input}."),
+            },
         };
         write!(f, "Invalid FIGI. {}", &msg)
     }
@@ -312,7 +474,425 @@ impl std::fmt::Display for InvalidFigi {
 
 impl std::error::Error for InvalidFigi {}
 
+impl InvalidFigi {
+    #[inline]
+    #[must_use]
+    /// The rejected input, which every variant carries.
+    pub fn input(&self) -> &str {
+        match self {
+            Self::Checksum(s)
+            | Self::FirstTwo(s)
+            | Self::Third(s)
+            | Self::Consonant(s)
+            | Self::ConsonantOrNumeric(s)
+            | Self::Length(s)
+            | Self::Confusable { input: s, .. } => s,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Which variant this is, without its data, for counting errors by kind.
+    pub fn kind(&self) -> InvalidFigiKind {
+        match self {
+            Self::Checksum(_) => InvalidFigiKind::Checksum,
+            Self::FirstTwo(_) => InvalidFigiKind::FirstTwo,
+            Self::Third(_) => InvalidFigiKind::Third,
+            Self::Consonant(_) => InvalidFigiKind::Consonant,
+            Self::ConsonantOrNumeric(_) => InvalidFigiKind::ConsonantOrNumeric,
+            Self::Length(_) => InvalidFigiKind::Length,
+            Self::Confusable { .. } => InvalidFigiKind::Confusable,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+/// The variants of [`InvalidFigi`] without their data, as returned by
+/// [`InvalidFigi::kind`].
+///
+/// Each kind has a dense [`InvalidFigiKind::index`], so per-kind counters can
+/// live in an array of [`InvalidFigiKind::COUNT`] entries.
+pub enum InvalidFigiKind {
+    /// [`InvalidFigi::Checksum`]
+    Checksum,
+    /// [`InvalidFigi::FirstTwo`]
+    FirstTwo,
+    /// [`InvalidFigi::Third`]
+    Third,
+    /// [`InvalidFigi::Consonant`]
+    Consonant,
+    /// [`InvalidFigi::ConsonantOrNumeric`]
+    ConsonantOrNumeric,
+    /// [`InvalidFigi::Length`]
+    Length,
+    /// [`InvalidFigi::Confusable`]
+    Confusable,
+}
+
+impl InvalidFigiKind {
+    /// Every kind, in [`InvalidFigiKind::index`] order.
+    pub const ALL: [Self; 7] = [
+        Self::Checksum,
+        Self::FirstTwo,
+        Self::Third,
+        Self::Consonant,
+        Self::ConsonantOrNumeric,
+        Self::Length,
+        Self::Confusable,
+    ];
+
+    /// The number of kinds.
+    pub const COUNT: usize = Self::ALL.len();
+
+    #[inline]
+    #[must_use]
+    /// This kind's position in [`InvalidFigiKind::ALL`], from 0 to
+    /// [`InvalidFigiKind::COUNT`] exclusive.
+    pub const fn index(self) -> usize {
+        self as usize
+    }
+}
+
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+/// Why [`Figi::from_str_ocr`] could not recover a FIGI.
+pub enum InvalidOcrFigi {
+    /// No reading of the OCR-ambiguous characters gives a valid FIGI. Holds the
+    /// error for the input as given.
+    NoCandidate(InvalidFigi),
+    /// More than one reading gives a valid FIGI.
+    Ambiguous(Vec<Figi>),
+}
+
+impl std::fmt::Display for InvalidOcrFigi {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match self {
+            Self::NoCandidate(e) => write!(f, "No OCR correction found. {e}"),
+            Self::Ambiguous(candidates) => {
+                write!(f, "Ambiguous OCR correction. Candidates:")?;
+                for c in candidates {
+                    write!(f, " {}", String::from(c))?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for InvalidOcrFigi {}
+
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+/// The outcome of [`classify`].
+pub enum FigiStatus {
+    /// The input is a valid FIGI.
+    Valid(Figi),
+    /// Every rule but the checksum holds; `corrected` has the check digit
+    /// recomputed from the first 11 characters.
+    ChecksumOnly {
+        /// The input with its check digit replaced.
+        corrected: Figi,
+    },
+    /// The input breaks a rule other than the checksum.
+    Structural(InvalidFigi),
+}
+
+#[must_use]
+/// Parse `s`, separating a wrong check digit, which is often a typo and can be
+/// corrected, from a structurally broken string.
+///
+/// # Example
+/// ```
+/// use symbology::ibrk_figi::{classify, FigiStatus};
+///
+/// assert!(matches!(classify("BBG000N88V36"), FigiStatus::Valid(_)));
+/// assert!(matches!(classify("BBG000N88V37"), FigiStatus::ChecksumOnly { .. }));
+/// assert!(matches!(classify("BBX000N88V36"), FigiStatus::Structural(_)));
+/// ```
+pub fn classify(s: &str) -> FigiStatus {
+    match s.parse::<Figi>() {
+        Ok(figi) => FigiStatus::Valid(figi),
+        Err(InvalidFigi::Checksum(_)) => {
+            // A checksum error means the structural checks passed on exactly
+            // 12 ASCII bytes.
+            let body: [char; 11] = std::array::from_fn(|i| s.as_bytes()[i] as char);
+            match Figi::with_computed_check_digit(&body) {
+                Ok(corrected) => FigiStatus::ChecksumOnly { corrected },
+                Err(e) => FigiStatus::Structural(e),
+            }
+        }
+        Err(e) => FigiStatus::Structural(e),
+    }
+}
+
+/// Parse one input of a batch API, counting the outcome in
+/// [`FigiMetrics`](crate::metrics::FigiMetrics) when the `metrics` feature is
+/// enabled.
+#[inline]
+fn parse_batched(s: &str) -> Result<Figi, InvalidFigi> {
+    let result = s.parse();
+    #[cfg(feature = "metrics")]
+    crate::metrics::FigiMetrics::global().record(&result);
+    result
+}
+
+/// Parse a list of FIGIs separated by commas and/or whitespace, as found in
+/// config files. Empty tokens, such as from `", ,"`, are skipped.
+///
+/// # Errors
+/// The 0-based index of the first invalid FIGI among the non-empty tokens,
+/// and its error.
+///
+/// # Example
+/// ```
+/// use symbology::ibrk_figi::{parse_list, InvalidFigi};
+///
+/// let figis = parse_list("BBG000N88V36, BBG000BD8ZK0 XCG00GFXXMR5").unwrap();
+/// assert_eq!(figis.len(), 3);
+///
+/// let (index, err) = parse_list("BBG000N88V36, XCG00GFXXMR3").unwrap_err();
+/// assert_eq!(index, 1);
+/// assert!(matches!(err, InvalidFigi::Checksum(_)));
+/// ```
+pub fn parse_list(s: &str) -> Result<Vec<Figi>, (usize, InvalidFigi)> {
+    s.split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|token| !token.is_empty())
+        .enumerate()
+        .map(|(i, token)| parse_batched(token).map_err(|e| (i, e)))
+        .collect()
+}
+
+/// Validate `s` and write its canonical form, trimmed and uppercase, to `out`
+/// without building an intermediate `String`. Nothing is written if `s` is
+/// invalid.
+///
+/// # Errors
+/// [`WriteCanonicalError::Invalid`] with the same error as parsing the
+/// trimmed, uppercased input, or [`WriteCanonicalError::Write`] if `out`
+/// fails, as a `Formatter` or a fixed-capacity writer can. Part of the FIGI
+/// may have been written by then. Writing to a `String` never fails.
+///
+/// # Example
+/// ```
+/// use symbology::ibrk_figi::write_canonical;
+///
+/// let mut out = String::new();
+/// write_canonical("  bbg000n88v36\n", &mut out).unwrap();
+/// assert_eq!(out, "BBG000N88V36");
+/// ```
+pub fn write_canonical<W: std::fmt::Write>(
+    s: &str,
+    out: &mut W,
+) -> Result<(), WriteCanonicalError> {
+    let s = s.trim();
+    let figi = match <&[u8; 12]>::try_from(s.as_bytes()) {
+        Ok(b) if s.is_ascii() => Figi::from_chars(&b.map(|b| char::from(b.to_ascii_uppercase())))?,
+        // Never valid; let `FromStr` choose the error, including confusables
+        _ => s.to_ascii_uppercase().parse()?,
+    };
+    for c in figi.chars() {
+        out.write_char(c).map_err(WriteCanonicalError::Write)?;
+    }
+    Ok(())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+/// Why [`write_canonical`] failed. The output can fail as well as the input,
+/// so this wraps [`InvalidFigi`] rather than returning it directly.
+pub enum WriteCanonicalError {
+    /// The input is not a valid FIGI.
+    Invalid(InvalidFigi),
+    /// The output returned an error.
+    Write(std::fmt::Error),
+}
+
+impl std::fmt::Display for WriteCanonicalError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match self {
+            Self::Invalid(e) => write!(f, "{e}"),
+            Self::Write(_) => write!(f, "Failed to write canonical FIGI"),
+        }
+    }
+}
+
+impl std::error::Error for WriteCanonicalError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Invalid(e) => Some(e),
+            Self::Write(e) => Some(e),
+        }
+    }
+}
+
+impl From<InvalidFigi> for WriteCanonicalError {
+    fn from(value: InvalidFigi) -> Self {
+        Self::Invalid(value)
+    }
+}
+
+/// How many offending inputs [`validate_report`] keeps per error variant.
+pub const REPORT_SAMPLES: usize = 3;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// The number of inputs rejected with one [`InvalidFigi`] variant.
+pub struct ErrorTally {
+    /// How many inputs failed this way.
+    pub count: usize,
+    /// The first [`REPORT_SAMPLES`] of them, verbatim.
+    pub samples: Vec<String>,
+}
+
+impl ErrorTally {
+    fn record(&mut self, input: &str) {
+        self.count += 1;
+        if self.samples.len() < REPORT_SAMPLES {
+            self.samples.push(input.to_owned());
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// The outcome of [`validate_report`]: valid inputs, plus one [`ErrorTally`]
+/// per [`InvalidFigiKind`].
+pub struct FigiReport {
+    /// Inputs that parsed as a valid FIGI.
+    pub valid: usize,
+    tallies: [ErrorTally; InvalidFigiKind::COUNT],
+}
+
+impl FigiReport {
+    #[must_use]
+    /// The inputs rejected with errors of `kind`.
+    pub fn tally(&self, kind: InvalidFigiKind) -> &ErrorTally {
+        &self.tallies[kind.index()]
+    }
+
+    #[must_use]
+    /// The number of inputs that were not valid FIGIs.
+    pub fn invalid(&self) -> usize {
+        self.tallies.iter().map(|t| t.count).sum()
+    }
+}
+
+#[must_use]
+/// Parse every input and count the outcomes, for data-quality reporting.
+///
+/// # Example
+/// ```
+/// use symbology::ibrk_figi::{validate_report, InvalidFigiKind};
+///
+/// let report = validate_report(["BBG000N88V36", "BBG000N88V37", "BBG000N88V3"].into_iter());
+/// assert_eq!(report.valid, 1);
+/// assert_eq!(report.tally(InvalidFigiKind::Checksum).count, 1);
+/// assert_eq!(report.tally(InvalidFigiKind::Length).samples, ["BBG000N88V3"]);
+/// ```
+pub fn validate_report<'a>(inputs: impl Iterator<Item = &'a str>) -> FigiReport {
+    let mut report = FigiReport::default();
+    for input in inputs {
+        match parse_batched(input) {
+            Ok(_) => report.valid += 1,
+            Err(e) => report.tallies[e.kind().index()].record(input),
+        }
+    }
+    report
+}
+
+#[inline(always)]
+/// Report a parse failure as a `tracing` event when the `tracing` feature is
+/// enabled; otherwise this is the identity function.
+fn trace_invalid(err: InvalidFigi) -> InvalidFigi {
+    #[cfg(feature = "tracing")]
+    {
+        let input: String = err.input().chars().take(32).collect();
+        tracing::debug!(variant = ?err.kind(), input, "invalid FIGI");
+    }
+    err
+}
+
+/// The most FIGIs [`Figi::repair_candidates`] can return: up to 3 per
+/// consonant position (1 and 2), 4 per body position (4 to 11), and 1 each
+/// for position 3 and the check digit. That is how many replacement
+/// characters at most share a check-digit contribution at each position.
+pub const MAX_REPAIR_CANDIDATES: usize = 40;
+
+/// Every character that is valid somewhere in a FIGI, in ASCII order: the
+/// digits, then the [`CONSONANTS`]. Positions 4 to 11 accept all of them.
+pub(crate) const FIGI_ALPHABET: [char; 31] = [
+    '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'B', 'C', 'D', 'F', 'G', 'H', 'J', 'K', 'L',
+    'M', 'N', 'P', 'Q', 'R', 'S', 'T', 'V', 'W', 'X', 'Y', 'Z',
+];
+
+/// The uppercase consonants, which positions 1 and 2 accept: [`FIGI_ALPHABET`]
+/// without its digits.
+pub(crate) const CONSONANTS: &[char] = FIGI_ALPHABET.split_at(10).1;
+
+/// Known certified-provider prefixes (positions 1 and 2) and the agency that
+/// issues FIGIs under them.
+///
+/// - `BB`: Bloomberg, which issues nearly all FIGIs in circulation.
+/// - `KK`: Kaiko, which issues FIGIs for digital assets.
+const ISSUING_AGENCIES: &[([char; 2], &str)] = &[(['B', 'B'], "Bloomberg"), (['K', 'K'], "Kaiko")];
+
 impl Figi {
+    #[inline]
+    /// Construct a new [`Figi`] from anything string-like: `&str`, `String`,
+    /// `&String`, `Box<str>` and so on.
+    ///
+    /// # Errors
+    /// Will error if `s` is not a valid FIGI code.
+    pub fn new(s: impl AsRef<str>) -> Result<Self, InvalidFigi> {
+        s.as_ref().parse()
+    }
+
+    #[inline]
+    /// Parse a line read from a file: exactly one trailing `\n` or `\r\n` is
+    /// removed first, and everything else is parsed strictly.
+    ///
+    /// # Errors
+    /// Will error if the rest of the line is not a valid FIGI code.
+    ///
+    /// # Example
+    /// ```
+    /// use symbology::ibrk_figi::Figi;
+    ///
+    /// assert!(Figi::from_line("BBG000N88V36\r\n").is_ok());
+    /// assert!(Figi::from_line("BBG000N88V36 \n").is_err());
+    /// ```
+    pub fn from_line(s: &str) -> Result<Self, InvalidFigi> {
+        let s = s
+            .strip_suffix('\n')
+            .map_or(s, |s| s.strip_suffix('\r').unwrap_or(s));
+        s.parse()
+    }
+
+    #[inline]
+    #[must_use]
+    /// A fixed, valid sentinel FIGI, `ZZG000000008`, for structs that need a
+    /// value before the real one is known. `ZZ` is not a known provider
+    /// prefix, so it should not collide with an issued FIGI, but nothing
+    /// stops one from doing so.
+    ///
+    /// `Figi` deliberately has no `Default`. Prefer `Option<Figi>` where the
+    /// field can be absent, and check [`Figi::is_placeholder`] before using a
+    /// value that may still be the sentinel.
+    pub const fn placeholder() -> Self {
+        use ConsonantOrNumeric::{Eight, Zero};
+        Figi {
+            pos_1: Consonant::Z,
+            pos_2: Consonant::Z,
+            pos_3: G,
+            pos_4_12: [Zero, Zero, Zero, Zero, Zero, Zero, Zero, Zero, Eight],
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Whether this is the [`Figi::placeholder`] sentinel.
+    pub fn is_placeholder(&self) -> bool {
+        *self == Self::placeholder()
+    }
+
     #[inline]
     /// Construct a new [`Figi`] from a sequence of 12 characters.
     ///
@@ -321,78 +901,815 @@ impl Figi {
     ///
     /// # Errors
     /// Will error if the provided charaters are not a valid FIGI code.
+    ///
+    /// # Panics
+    /// Never. The input is a fixed-size array that is destructured rather than
+    /// indexed, and the checksum arithmetic cannot overflow, so there are no
+    /// bounds or overflow checks on this path.
+    ///
+    /// Checks run left to right, so the first failing rule determines the
+    /// error: a reserved prefix is reported as [`InvalidFigi::FirstTwo`] even
+    /// when position 3 is also wrong, and `GG`, `GB` and `GH` are rejected as
+    /// reserved prefixes rather than by the position-3 `G` rule.
     pub fn from_chars(s: &[char; 12]) -> Result<Self, InvalidFigi> {
-        let (pos_1, pos_2) = match (s[0], s[1]) {
-            ('B', 'S' | 'M') | ('G', 'G' | 'B' | 'H') | ('K', 'Y') | ('V', 'G') => {
-                return Err(InvalidFigi::FirstTwo(s.iter().collect()))
-            }
-            (c1, c2) => (
-                Consonant::try_from(c1).map_err(|_| InvalidFigi::Consonant(s.iter().collect()))?,
-                Consonant::try_from(c2).map_err(|_| InvalidFigi::Consonant(s.iter().collect()))?,
-            ),
-        };
-        let pos_3 = if s[2] == 'G' {
+        Self::from_chars_untraced(s).map_err(trace_invalid)
+    }
+
+    #[inline]
+    /// Validate `b` without requiring UTF-8. Bytes outside ASCII are reported
+    /// as whichever character rule they break at their position.
+    pub(crate) fn from_bytes(b: &[u8]) -> Result<Self, InvalidFigi> {
+        let b: &[u8; 12] = b.try_into().map_err(|_| {
+            trace_invalid(InvalidFigi::Length(String::from_utf8_lossy(b).into_owned()))
+        })?;
+        Self::from_chars(&b.map(char::from))
+    }
+
+    #[inline]
+    fn from_chars_untraced(s: &[char; 12]) -> Result<Self, InvalidFigi> {
+        let out = Self::from_chars_structural(s)?;
+        if out.is_valid() {
+            Ok(out)
+        } else {
+            Err(InvalidFigi::Checksum(s.iter().collect()))
+        }
+    }
+
+    #[inline]
+    /// Check every rule except the checksum.
+    fn from_chars_structural(s: &[char; 12]) -> Result<Self, InvalidFigi> {
+        Self::check_structure(s).map_err(|e| e.with_input(s.iter().collect()))
+    }
+
+    #[inline]
+    /// [`Figi::from_chars_structural`] without building the error's `String`,
+    /// so it never allocates.
+    fn check_structure(s: &[char; 12]) -> Result<Self, StructuralError> {
+        // Destructure once so no position is reached through a bounds check
+        let [c1, c2, c3, c4, c5, c6, c7, c8, c9, c10, c11, c12] = *s;
+        if is_reserved_prefix(c1, c2) {
+            return Err(StructuralError::FirstTwo);
+        }
+        let pos_1 = Consonant::try_from(c1).map_err(|_| StructuralError::Consonant)?;
+        let pos_2 = Consonant::try_from(c2).map_err(|_| StructuralError::Consonant)?;
+        let pos_3 = if c3 == 'G' {
             G
         } else {
-            return Err(InvalidFigi::Third(s.iter().collect()));
+            return Err(StructuralError::Third);
         };
+        let conso_numeric =
+            |c| ConsonantOrNumeric::try_from(c).map_err(|_| StructuralError::ConsonantOrNumeric);
         let pos_4_12 = [
-            ConsonantOrNumeric::try_from(s[3])
-                .map_err(|_| InvalidFigi::ConsonantOrNumeric(s.iter().collect()))?,
-            ConsonantOrNumeric::try_from(s[4])
-                .map_err(|_| InvalidFigi::ConsonantOrNumeric(s.iter().collect()))?,
-            ConsonantOrNumeric::try_from(s[5])
-                .map_err(|_| InvalidFigi::ConsonantOrNumeric(s.iter().collect()))?,
-            ConsonantOrNumeric::try_from(s[6])
-                .map_err(|_| InvalidFigi::ConsonantOrNumeric(s.iter().collect()))?,
-            ConsonantOrNumeric::try_from(s[7])
-                .map_err(|_| InvalidFigi::ConsonantOrNumeric(s.iter().collect()))?,
-            ConsonantOrNumeric::try_from(s[8])
-                .map_err(|_| InvalidFigi::ConsonantOrNumeric(s.iter().collect()))?,
-            ConsonantOrNumeric::try_from(s[9])
-                .map_err(|_| InvalidFigi::ConsonantOrNumeric(s.iter().collect()))?,
-            ConsonantOrNumeric::try_from(s[10])
-                .map_err(|_| InvalidFigi::ConsonantOrNumeric(s.iter().collect()))?,
-            ConsonantOrNumeric::try_from(s[11])
-                .map_err(|_| InvalidFigi::ConsonantOrNumeric(s.iter().collect()))?,
+            conso_numeric(c4)?,
+            conso_numeric(c5)?,
+            conso_numeric(c6)?,
+            conso_numeric(c7)?,
+            conso_numeric(c8)?,
+            conso_numeric(c9)?,
+            conso_numeric(c10)?,
+            conso_numeric(c11)?,
+            conso_numeric(c12)?,
         ];
 
-        let out = Self {
+        Ok(Self {
             pos_1,
             pos_2,
             pos_3,
             pos_4_12,
+        })
+    }
+
+    #[inline]
+    /// Construct a new [`Figi`] from its first eleven characters, appending the
+    /// computed check digit.
+    ///
+    /// # Errors
+    /// Will error if the provided characters break any rule other than the
+    /// checksum.
+    pub fn with_computed_check_digit(body: &[char; 11]) -> Result<Self, InvalidFigi> {
+        let mut s = ['0'; 12];
+        s[..11].copy_from_slice(body);
+        let check_digit = Self::from_chars_structural(&s)?.compute_check_digit();
+        // The check digit is reduced mod 10, so this is always `0`-`9`
+        s[11] = char::from(b'0' + check_digit);
+        Self::from_chars_structural(&s)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Construct a [`Figi`] from 12 ASCII bytes without validating them, for
+    /// data that was validated when it was first stored.
+    ///
+    /// Debug builds still validate and panic on invalid input.
+    ///
+    /// # Safety
+    /// `s` must be a valid FIGI, i.e. [`Figi::from_chars`] would accept it. In
+    /// particular every byte must be an ASCII digit or uppercase consonant;
+    /// any other byte is undefined behavior.
+    pub unsafe fn from_validated_unchecked(s: [u8; 12]) -> Figi {
+        debug_assert!(
+            Self::from_chars(&s.map(char::from)).is_ok(),
+            "from_validated_unchecked called with an invalid FIGI"
+        );
+        let discriminant = |c: u8| {
+            if c.is_ascii_digit() {
+                c - b'0'
+            } else {
+                c - b'A' + 10
+            }
         };
-        if out.is_valid() {
-            Ok(out)
+        // SAFETY: the caller guarantees positions 1 and 2 are consonants and
+        // the rest are consonants or digits, whose discriminants are exactly
+        // the ones these `repr(u8)` enums define.
+        unsafe {
+            Figi {
+                pos_1: std::mem::transmute::<u8, Consonant>(discriminant(s[0])),
+                pos_2: std::mem::transmute::<u8, Consonant>(discriminant(s[1])),
+                pos_3: G,
+                pos_4_12: std::array::from_fn(|i| {
+                    std::mem::transmute::<u8, ConsonantOrNumeric>(discriminant(s[i + 3]))
+                }),
+            }
+        }
+    }
+
+    /// Lazily yield every valid FIGI with the given prefix, in ascending order,
+    /// by counting through positions 4-11 and appending the computed check
+    /// digit.
+    ///
+    /// The space is 31^8, about 8.5 * 10^11 FIGIs per prefix, so take only
+    /// what you need. An invalid or reserved prefix yields nothing.
+    pub fn enumerate_bodies(prefix: [char; 2]) -> impl Iterator<Item = Figi> {
+        const ALPHABET: &[u8; 31] = b"0123456789BCDFGHJKLMNPQRSTVWXYZ";
+        let [p1, p2] = prefix;
+        let body = move |mut n: u64| {
+            let mut body = [p1, p2, 'G', '0', '0', '0', '0', '0', '0', '0', '0'];
+            for c in body[3..].iter_mut().rev() {
+                *c = char::from(ALPHABET[(n % 31) as usize]);
+                n /= 31;
+            }
+            body
+        };
+        let count = if Self::with_computed_check_digit(&body(0)).is_ok() {
+            31u64.pow(8)
         } else {
-            Err(InvalidFigi::Checksum(s.iter().collect()))
+            0
+        };
+        (0..count).filter_map(move |n| Self::with_computed_check_digit(&body(n)).ok())
+    }
+
+    #[inline]
+    #[must_use]
+    /// Positions 4 through 12: the body after the prefix and `G`, including
+    /// the check digit.
+    pub fn body_only(&self) -> [char; 9] {
+        self.pos_4_12.map(char::from)
+    }
+
+    #[inline]
+    /// Reassemble a [`Figi`] from its two-character prefix and the 9-character
+    /// body returned by [`Figi::body_only`].
+    ///
+    /// The check digit is not recomputed: the one supplied as the last
+    /// character of `body` is validated like any other input.
+    ///
+    /// # Errors
+    /// Will error if the reassembled characters are not a valid FIGI code.
+    pub fn from_body(prefix: [char; 2], body: &[char; 9]) -> Result<Self, InvalidFigi> {
+        let mut s = ['G'; 12];
+        s[..2].copy_from_slice(&prefix);
+        s[3..].copy_from_slice(body);
+        Self::from_chars(&s)
+    }
+
+    #[must_use]
+    /// Every valid FIGI that differs from `s` in exactly one character,
+    /// including the check digit, for data-repair tooling. A single typo is
+    /// usually undone by one of them, but which one cannot be known.
+    ///
+    /// # Returns
+    /// Candidates ordered by the position changed, then by the replacement
+    /// character, and never more than [`MAX_REPAIR_CANDIDATES`]. `s` itself is
+    /// not included even if it is valid. Empty unless `s` is 12 characters.
+    ///
+    /// # Example
+    /// ```
+    /// use symbology::ibrk_figi::Figi;
+    ///
+    /// let aapl: Figi = "BBG000N88V36".parse().unwrap();
+    /// assert!(Figi::repair_candidates("BBG000N89V36").contains(&aapl));
+    /// // A wrong check digit is repaired last
+    /// assert_eq!(Figi::repair_candidates("BBG000N88V37").last(), Some(&aapl));
+    /// ```
+    pub fn repair_candidates(s: &str) -> Vec<Self> {
+        let Ok(chars) = <[char; 12]>::try_from(s.chars().collect::<Vec<_>>()) else {
+            return Vec::new();
+        };
+        let mut candidates = Vec::new();
+        for i in 0..chars.len() {
+            for c in FIGI_ALPHABET {
+                if c == chars[i] {
+                    continue;
+                }
+                let mut candidate = chars;
+                candidate[i] = c;
+                if let Ok(figi) = Self::from_chars_untraced(&candidate) {
+                    candidates.push(figi);
+                }
+            }
+        }
+        candidates
+    }
+
+    /// Parse a FIGI that may have been through OCR.
+    ///
+    /// If `s` is not valid as given, the common confusions `O`/`0`, `I`/`1`,
+    /// `S`/`5` and `8`/`B` are tried. `O` and `I` can never appear in a FIGI so
+    /// they are always read as `0` and `1`; the other pairs are tried both ways.
+    ///
+    /// # Returns
+    /// `s` itself if it is valid, otherwise the only reading that is valid.
+    ///
+    /// # Errors
+    /// Will error if no reading, or more than one reading, is a valid FIGI.
+    pub fn from_str_ocr(s: &str) -> Result<Self, InvalidOcrFigi> {
+        let err = match s.parse::<Self>() {
+            Ok(figi) => return Ok(figi),
+            Err(e) => e,
+        };
+        let Ok(b) = <[u8; 12]>::try_from(s.as_bytes()) else {
+            return Err(InvalidOcrFigi::NoCandidate(err));
+        };
+        let options = b.map(|c| match c {
+            b'O' | b'0' => vec!['0'],
+            b'I' | b'1' => vec!['1'],
+            b'S' | b'5' => vec!['S', '5'],
+            b'B' | b'8' => vec!['B', '8'],
+            c => vec![c as char],
+        });
+        let readings: usize = options.iter().map(Vec::len).product();
+        let mut candidates = Vec::new();
+        for mut n in 0..readings {
+            let mut chars = ['0'; 12];
+            for (c, opts) in chars.iter_mut().zip(&options) {
+                *c = opts[n % opts.len()];
+                n /= opts.len();
+            }
+            if let Ok(figi) = Self::from_chars_untraced(&chars) {
+                candidates.push(figi);
+            }
+        }
+        match candidates.len() {
+            0 => Err(InvalidOcrFigi::NoCandidate(err)),
+            1 => Ok(candidates[0]),
+            _ => Err(InvalidOcrFigi::Ambiguous(candidates)),
+        }
+    }
+
+    #[must_use]
+    /// Parse every input, in parallel across all cores when the `rayon`
+    /// feature is enabled and sequentially otherwise.
+    ///
+    /// # Returns
+    /// One result per input, in input order.
+    pub fn par_parse_many(inputs: &[&str]) -> Vec<Result<Self, InvalidFigi>> {
+        #[cfg(feature = "rayon")]
+        {
+            use rayon::prelude::*;
+            inputs.par_iter().map(|s| parse_batched(s)).collect()
+        }
+        #[cfg(not(feature = "rayon"))]
+        {
+            inputs.iter().map(|s| parse_batched(s)).collect()
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// The first three characters: the two-consonant provider prefix and `G`.
+    /// Agrees with [`crate::figi::Figi::prefix3`].
+    pub fn prefix3(&self) -> [char; 3] {
+        [self.pos_1.into(), self.pos_2.into(), self.pos_3.into()]
+    }
+
+    #[inline]
+    #[must_use]
+    /// The certified provider that issued this FIGI, looked up from its prefix.
+    ///
+    /// # Returns
+    /// `None` if the prefix is not in the crate's table of known agencies.
+    pub fn issuing_agency(&self) -> Option<&'static str> {
+        let prefix: [char; 2] = [self.pos_1.into(), self.pos_2.into()];
+        ISSUING_AGENCIES
+            .iter()
+            .find(|(p, _)| *p == prefix)
+            .map(|(_, agency)| *agency)
+    }
+
+    #[must_use]
+    /// This FIGI with all but the first 3 and last 2 characters replaced by
+    /// `*`, for logging: `BBG*******36`.
+    pub fn masked(&self) -> String {
+        self.masked_with(3, 2)
+    }
+
+    #[must_use]
+    /// This FIGI with all but the first `keep_start` and last `keep_end`
+    /// characters replaced by `*`. Nothing is masked if they cover all 12.
+    ///
+    /// # Example
+    /// ```
+    /// use symbology::ibrk_figi::Figi;
+    ///
+    /// let figi: Figi = "BBG000N88V36".parse().unwrap();
+    /// assert_eq!(figi.masked(), "BBG*******36");
+    /// assert_eq!(figi.masked_with(6, 2), "BBG000****36");
+    /// ```
+    pub fn masked_with(&self, keep_start: usize, keep_end: usize) -> String {
+        let mask_end = 12usize.saturating_sub(keep_end);
+        self.chars()
+            .enumerate()
+            .map(|(i, c)| {
+                if i >= keep_start && i < mask_end {
+                    '*'
+                } else {
+                    c
+                }
+            })
+            .collect()
+    }
+
+    #[inline]
+    #[must_use]
+    /// The 12 ASCII bytes of this FIGI, without allocating.
+    pub fn to_ascii_bytes(&self) -> [u8; 12] {
+        let mut buf = [0u8; 12];
+        for (b, c) in buf.iter_mut().zip(self.chars()) {
+            *b = c as u8;
         }
+        buf
+    }
+
+    #[inline]
+    #[must_use]
+    /// A 64-bit key for systems that only accept integers: 64-bit FNV-1a over
+    /// the 12 ASCII bytes. The algorithm is part of the API, so a FIGI maps to
+    /// the same value across runs, platforms and crate versions.
+    ///
+    /// Distinct FIGIs can collide, as with any hash. Where a collision-free
+    /// key is needed, use the [`PackedFigi`] bytes as a big-endian `u64`
+    /// instead, which are injective but follow the crate's packing layout.
+    pub fn stable_hash64(&self) -> u64 {
+        const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        const PRIME: u64 = 0x0000_0100_0000_01b3;
+        self.to_ascii_bytes()
+            .iter()
+            .fold(OFFSET_BASIS, |h, b| (h ^ u64::from(*b)).wrapping_mul(PRIME))
+    }
+
+    #[inline]
+    /// The 12 characters of this FIGI, produced lazily without allocating.
+    pub fn chars(&self) -> impl Iterator<Item = char> {
+        let head: [char; 3] = [self.pos_1.into(), self.pos_2.into(), self.pos_3.into()];
+        head.into_iter()
+            .chain(self.pos_4_12.into_iter().map(char::from))
+    }
+
+    #[inline]
+    #[must_use]
+    /// The number of positions, out of 12, at which `self` and `other` differ.
+    pub fn hamming_distance(&self, other: &Figi) -> usize {
+        self.chars()
+            .zip(other.chars())
+            .filter(|(a, b)| a != b)
+            .count()
+    }
+
+    #[inline]
+    #[must_use]
+    /// The numeric value of the check digit (position 12).
+    ///
+    /// # Returns
+    /// A value in `0..=9`; validation guarantees the check digit is numeric.
+    pub fn checksum_digit_value(&self) -> u8 {
+        self.pos_4_12[self.pos_4_12.len() - 1] as u8
     }
 
     #[inline]
     fn is_valid(&self) -> bool {
-        let mut sum = sum_digits_sub_100(self.pos_1 as u8)
-            + sum_digits_sub_100(self.pos_2 as u8 * 2)
-            + sum_digits_sub_100(G.into());
+        self.checksum_digit_value() == self.compute_check_digit()
+    }
 
+    #[inline]
+    /// Compute the mod-10 check digit from positions 1 through 11, ignoring
+    /// whatever is stored at position 12.
+    fn compute_check_digit(&self) -> u8 {
+        let mut sum = check_digit_term(self.pos_1 as u8, 1)
+            + check_digit_term(self.pos_2 as u8, 2)
+            + check_digit_term(G.into(), 3);
         for (i, c) in self.pos_4_12[..self.pos_4_12.len() - 1].iter().enumerate() {
-            if i % 2 == 0 {
-                sum += sum_digits_sub_100(2 * *c as u8);
-            } else {
-                sum += sum_digits_sub_100(*c as u8);
-            }
+            sum += check_digit_term(*c as u8, i + 4);
         }
-        self.pos_4_12[self.pos_4_12.len() - 1] as u8 == (10 - sum % 10) % 10
+        check_digit_from_sum(sum)
     }
 }
 
+/// Whether `b` is a valid FIGI. Unlike parsing, this never allocates, so it
+/// is safe to call across an FFI boundary.
+#[cfg(feature = "ffi")]
+pub(crate) fn is_valid_bytes(b: &[u8]) -> bool {
+    let Ok(b) = <&[u8; 12]>::try_from(b) else {
+        return false;
+    };
+    Figi::check_structure(&b.map(char::from)).is_ok_and(|figi| figi.is_valid())
+}
+
+/// Whether positions 1 and 2 form a prefix the standard reserves, as a lookup
+/// in [`RESERVED_PREFIXES`].
+#[inline]
+pub(crate) const fn is_reserved_prefix(c1: char, c2: char) -> bool {
+    if c1.is_ascii_uppercase() && c2.is_ascii_uppercase() {
+        RESERVED_PREFIXES[c1 as usize - 'A' as usize][c2 as usize - 'A' as usize]
+    } else {
+        false
+    }
+}
+
+/// `RESERVED_PREFIXES[i][j]` is whether the `i`th and `j`th letters of the
+/// alphabet (0 for `A`) form a reserved prefix. Built at compile time from
+/// [`reserved_prefix_match`].
+const RESERVED_PREFIXES: [[bool; 26]; 26] = {
+    let mut table = [[false; 26]; 26];
+    let mut i = 0;
+    while i < 26 {
+        let mut j = 0;
+        while j < 26 {
+            table[i][j] = reserved_prefix_match((b'A' + i as u8) as char, (b'A' + j as u8) as char);
+            j += 1;
+        }
+        i += 1;
+    }
+    table
+};
+
+/// The reserved prefixes as listed in the standard: BS, BM, GG, GB, GH, KY
+/// and VG.
+const fn reserved_prefix_match(c1: char, c2: char) -> bool {
+    matches!(
+        (c1, c2),
+        ('B', 'S' | 'M') | ('G', 'G' | 'B' | 'H') | ('K', 'Y') | ('V', 'G')
+    )
+}
+
+/// The value the check-digit algorithm gives a [`FIGI_ALPHABET`] character:
+/// the digit itself, or 10 plus the letter's index in the alphabet (`B` is
+/// 11). This is also the character's `ConsonantOrNumeric` discriminant.
+#[inline]
+pub(crate) const fn check_digit_value(c: char) -> u8 {
+    if c.is_ascii_digit() {
+        c as u8 - b'0'
+    } else {
+        c as u8 - b'A' + 10
+    }
+}
+
+/// What a character with check-digit `value` at 1-based `position` (1 to 11)
+/// adds to the check-digit sum: the value, doubled at even positions, with the
+/// digits of the result summed.
+#[inline]
+pub(crate) const fn check_digit_term(value: u8, position: usize) -> u8 {
+    sum_digits_sub_100(if position % 2 == 0 { value * 2 } else { value })
+}
+
+/// The check digit for a sum of [`check_digit_term`]s.
+#[inline]
+pub(crate) const fn check_digit_from_sum(sum: u8) -> u8 {
+    (10 - sum % 10) % 10
+}
+
 #[inline]
 const fn sum_digits_sub_100(n: u8) -> u8 {
     let rem = n % 10;
     rem + (n - rem) / 10
 }
 
+/// Map an enum discriminant back to the character it encodes.
+#[inline]
+const fn discriminant_char(d: u8) -> char {
+    if d < 10 {
+        (b'0' + d) as char
+    } else {
+        (b'A' + d - 10) as char
+    }
+}
+
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+/// A [`Figi`] bit-packed into 8 bytes.
+///
+/// Positions 1, 2 and 4-11 are stored as their 6-bit enum discriminants, most
+/// significant first, followed by the check digit in the low 4 bits. Position 3
+/// is always `G` and is not stored. Because discriminants follow ASCII order,
+/// packed values sort the same way as the FIGI strings.
+pub struct PackedFigi([u8; 8]);
+
+impl Figi {
+    #[inline]
+    #[must_use]
+    /// Pack this FIGI into its compact 8-byte form.
+    pub fn pack(&self) -> PackedFigi {
+        let mut bits = (self.pos_1 as u64) << 58 | (self.pos_2 as u64) << 52;
+        for (i, c) in self.pos_4_12[..8].iter().enumerate() {
+            bits |= (*c as u64) << (46 - 6 * i);
+        }
+        bits |= u64::from(self.checksum_digit_value());
+        PackedFigi(bits.to_be_bytes())
+    }
+
+    /// Write the 12 ASCII bytes of this FIGI. There is no length prefix, since
+    /// the length is fixed.
+    ///
+    /// # Errors
+    /// Any error from `w`.
+    pub fn write_to<W: std::io::Write>(&self, w: &mut W) -> std::io::Result<()> {
+        w.write_all(&self.to_ascii_bytes())
+    }
+
+    /// Read 12 ASCII bytes written by [`Figi::write_to`].
+    ///
+    /// # Errors
+    /// Any error from `r`, including [`std::io::ErrorKind::UnexpectedEof`] if
+    /// fewer than 12 bytes remain. Bytes that are not a valid FIGI give
+    /// [`std::io::ErrorKind::InvalidData`] wrapping the [`InvalidFigi`].
+    pub fn read_from<R: std::io::Read>(r: &mut R) -> std::io::Result<Figi> {
+        let mut buf = [0u8; 12];
+        r.read_exact(&mut buf)?;
+        Self::from_chars(&buf.map(char::from))
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+    }
+}
+
+impl PackedFigi {
+    #[inline]
+    #[must_use]
+    /// Recover the [`Figi`] this was packed from.
+    pub fn unpack(&self) -> Figi {
+        Figi::from_chars_structural(&decode_packed(self.0))
+            .expect("a PackedFigi is only built from a valid Figi")
+    }
+
+    #[inline]
+    #[must_use]
+    /// The packed bytes.
+    pub fn to_bytes(self) -> [u8; 8] {
+        self.0
+    }
+}
+
+impl TryFrom<[u8; 8]> for PackedFigi {
+    type Error = InvalidFigi;
+
+    /// Validate packed bytes from an untrusted source, such as the wire.
+    fn try_from(bytes: [u8; 8]) -> Result<Self, Self::Error> {
+        Ok(Figi::from_chars(&decode_packed(bytes))?.pack())
+    }
+}
+
+/// Decode packed bytes into the 12 characters they represent, without
+/// validating them.
+fn decode_packed(bytes: [u8; 8]) -> [char; 12] {
+    let bits = u64::from_be_bytes(bytes);
+    let at = |shift: usize| discriminant_char(((bits >> shift) & 0x3F) as u8);
+    let mut s = ['G'; 12];
+    s[0] = at(58);
+    s[1] = at(52);
+    for (i, c) in s[3..11].iter_mut().enumerate() {
+        *c = at(46 - 6 * i);
+    }
+    s[11] = discriminant_char((bits & 0xF) as u8);
+    s
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// A FIGI prefix with a trailing `*` wildcard, such as `BBG000*`, for matching
+/// a family of FIGIs. A pattern without `*` must be a complete, valid FIGI and
+/// matches only that FIGI.
+///
+/// # Example
+/// ```
+/// use symbology::ibrk_figi::{Figi, FigiPattern};
+///
+/// let pattern: FigiPattern = "BBG000*".parse().unwrap();
+/// assert!(pattern.matches(&"BBG000N88V36".parse::<Figi>().unwrap()));
+/// assert!(!pattern.matches(&"XCG00GFXXMR5".parse::<Figi>().unwrap()));
+/// ```
+pub struct FigiPattern {
+    prefix: String,
+}
+
+impl FigiPattern {
+    #[inline]
+    #[must_use]
+    /// Whether `figi` starts with this pattern's prefix.
+    pub fn matches(&self, figi: &Figi) -> bool {
+        self.prefix.chars().zip(figi.chars()).all(|(p, c)| p == c)
+    }
+}
+
+impl std::str::FromStr for FigiPattern {
+    type Err = InvalidFigi;
+
+    /// Each character before the `*` must be legal at its position, and the
+    /// prefix may be at most 11 characters long.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some(prefix) = s.strip_suffix('*') else {
+            let figi: Figi = s.parse()?;
+            return Ok(Self {
+                prefix: String::from(&figi),
+            });
+        };
+        let chars: Vec<char> = prefix.chars().collect();
+        if chars.len() > 11 {
+            return Err(InvalidFigi::Length(s.to_owned()));
+        }
+        if let [c1, c2, ..] = chars[..] {
+            if is_reserved_prefix(c1, c2) {
+                return Err(InvalidFigi::FirstTwo(s.to_owned()));
+            }
+        }
+        for (i, &c) in chars.iter().enumerate() {
+            let err = match i {
+                0 | 1 if Consonant::try_from(c).is_err() => InvalidFigi::Consonant,
+                2 if c != 'G' => InvalidFigi::Third,
+                3.. if ConsonantOrNumeric::try_from(c).is_err() => InvalidFigi::ConsonantOrNumeric,
+                _ => continue,
+            };
+            return Err(err(s.to_owned()));
+        }
+        Ok(Self {
+            prefix: prefix.to_owned(),
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    //! Human-readable formats use the 12-character string; compact formats use
+    //! the 8 bytes of the [`PackedFigi`].
+
+    use super::{Figi, PackedFigi};
+    use serde::de::{self, Visitor};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    impl Serialize for Figi {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            if serializer.is_human_readable() {
+                serializer.serialize_str(&String::from(self))
+            } else {
+                serializer.serialize_bytes(&self.pack().to_bytes())
+            }
+        }
+    }
+
+    struct FigiVisitor;
+
+    impl<'de> Visitor<'de> for FigiVisitor {
+        type Value = Figi;
+
+        fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.write_str("a FIGI string or 8 packed bytes")
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Figi, E> {
+            v.parse().map_err(E::custom)
+        }
+
+        fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Figi, E> {
+            let bytes = <[u8; 8]>::try_from(v).map_err(|_| E::invalid_length(v.len(), &self))?;
+            PackedFigi::try_from(bytes)
+                .map(|p| p.unpack())
+                .map_err(E::custom)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Figi {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            if deserializer.is_human_readable() {
+                deserializer.deserialize_str(FigiVisitor)
+            } else {
+                deserializer.deserialize_bytes(FigiVisitor)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "rkyv")]
+pub use rkyv_impl::ArchivedFigi;
+
+#[cfg(feature = "rkyv")]
+mod rkyv_impl {
+    //! Archived as the 12 ASCII bytes. Zero-copy access through
+    //! [`ArchivedFigi`] trusts the archive; deserializing into a [`Figi`]
+    //! validates it.
+
+    use super::{Figi, InvalidFigi};
+    use rkyv::munge::munge;
+    use rkyv::rancor::{Fallible, Source};
+    use rkyv::{Archive, Deserialize, Place, Portable, Serialize};
+
+    #[derive(Debug, PartialEq, Eq, Portable, rkyv::bytecheck::CheckBytes)]
+    #[bytecheck(crate = rkyv::bytecheck)]
+    #[repr(transparent)]
+    /// The archived form of a [`Figi`]: its 12 ASCII bytes, read in place.
+    /// Accessing it does not check that the bytes are a valid FIGI.
+    pub struct ArchivedFigi([u8; 12]);
+
+    impl ArchivedFigi {
+        #[must_use]
+        /// The archived bytes, as written unless the archive is corrupt.
+        pub fn as_bytes(&self) -> &[u8; 12] {
+            &self.0
+        }
+
+        /// Validate the archived bytes, without a deserializer.
+        ///
+        /// # Errors
+        /// If the archived bytes are not a valid FIGI.
+        pub fn to_figi(&self) -> Result<Figi, InvalidFigi> {
+            Figi::try_from(self.0)
+        }
+    }
+
+    impl Archive for Figi {
+        type Archived = ArchivedFigi;
+        type Resolver = ();
+
+        fn resolve(&self, (): (), out: Place<ArchivedFigi>) {
+            munge!(let ArchivedFigi(bytes) = out);
+            bytes.write(self.to_ascii_bytes());
+        }
+    }
+
+    impl<S: Fallible + ?Sized> Serialize<S> for Figi {
+        fn serialize(&self, _: &mut S) -> Result<(), S::Error> {
+            Ok(())
+        }
+    }
+
+    impl<D: Fallible + ?Sized> Deserialize<Figi, D> for ArchivedFigi
+    where
+        D::Error: Source,
+    {
+        fn deserialize(&self, _: &mut D) -> Result<Figi, D::Error> {
+            self.to_figi().map_err(D::Error::new)
+        }
+    }
+}
+
+#[cfg(feature = "sqlx")]
+mod sqlx_impl {
+    //! Stored as `TEXT`, and validated with [`FromStr`](std::str::FromStr) on
+    //! decode.
+
+    use super::Figi;
+    use sqlx::encode::IsNull;
+    use sqlx::error::BoxDynError;
+    use sqlx::{Database, Decode, Encode, Type};
+
+    impl<DB: Database> Type<DB> for Figi
+    where
+        str: Type<DB>,
+    {
+        fn type_info() -> DB::TypeInfo {
+            <str as Type<DB>>::type_info()
+        }
+
+        fn compatible(ty: &DB::TypeInfo) -> bool {
+            <str as Type<DB>>::compatible(ty)
+        }
+    }
+
+    impl<'q, DB: Database> Encode<'q, DB> for Figi
+    where
+        String: Encode<'q, DB>,
+    {
+        fn encode_by_ref(
+            &self,
+            buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+        ) -> Result<IsNull, BoxDynError> {
+            String::from(self).encode(buf)
+        }
+    }
+
+    impl<'r, DB: Database> Decode<'r, DB> for Figi
+    where
+        &'r str: Decode<'r, DB>,
+    {
+        fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+            Ok(<&str as Decode<DB>>::decode(value)?.parse()?)
+        }
+    }
+}
+
 #[test]
 fn test_figi() -> Result<(), InvalidFigi> {
     let aapl = "BBG000N88V36".parse::<Figi>()?; // AAPL US Equity
@@ -401,3 +1718,935 @@ fn test_figi() -> Result<(), InvalidFigi> {
     assert!(tsm.is_valid());
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::seq::SliceRandom;
+    use rand::SeedableRng;
+
+    /// A random, structurally valid 11-character FIGI body.
+    fn random_body(rng: &mut StdRng) -> [char; 11] {
+        let mut body = ['G'; 11];
+        loop {
+            body[0] = *CONSONANTS.choose(rng).unwrap();
+            body[1] = *CONSONANTS.choose(rng).unwrap();
+            if !is_reserved_prefix(body[0], body[1]) {
+                break;
+            }
+        }
+        for c in &mut body[3..] {
+            *c = *FIGI_ALPHABET.choose(rng).unwrap();
+        }
+        body
+    }
+
+    #[test]
+    fn test_exactly_one_check_digit_per_body() {
+        // Seeded so it runs without the `proptest` feature; `proptest_ext`
+        // checks the same property with shrinking.
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..1_000 {
+            let body = random_body(&mut rng);
+            let mut s = ['0'; 12];
+            s[..11].copy_from_slice(&body);
+            let valid: Vec<Figi> = ('0'..='9')
+                .filter_map(|d| {
+                    s[11] = d;
+                    Figi::from_chars(&s).ok()
+                })
+                .collect();
+            assert_eq!(valid.len(), 1, "{body:?}");
+            assert_eq!(
+                valid[0].checksum_digit_value(),
+                valid[0].compute_check_digit()
+            );
+        }
+    }
+
+    #[test]
+    fn test_pack_round_trip() -> Result<(), InvalidFigi> {
+        assert_eq!(std::mem::size_of::<PackedFigi>(), 8);
+        for s in ["BBG000N88V36", "BBG000BD8ZK0"] {
+            let figi = s.parse::<Figi>()?;
+            assert_eq!(figi.pack().unpack(), figi);
+            assert_eq!(String::from(&figi.pack().unpack()), s);
+        }
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..1_000 {
+            let body = random_body(&mut rng);
+            let mut s = ['0'; 12];
+            s[..11].copy_from_slice(&body);
+            for d in '0'..='9' {
+                s[11] = d;
+                if let Ok(figi) = Figi::from_chars(&s) {
+                    assert_eq!(figi.pack().unpack(), figi);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_packed_order_matches_string_order() -> Result<(), InvalidFigi> {
+        let a = "BBG000BD8ZK0".parse::<Figi>()?;
+        let b = "BBG000N88V36".parse::<Figi>()?;
+        assert!(a.pack() < b.pack());
+        Ok(())
+    }
+
+    #[test]
+    fn test_chars() -> Result<(), InvalidFigi> {
+        let figi = "BBG000N88V36".parse::<Figi>()?;
+        assert_eq!(figi.chars().count(), 12);
+        assert_eq!(figi.chars().collect::<String>(), String::from(&figi));
+        Ok(())
+    }
+
+    #[test]
+    fn test_body_round_trip() -> Result<(), InvalidFigi> {
+        let figi = "BBG000N88V36".parse::<Figi>()?;
+        let body = figi.body_only();
+        assert_eq!(body.iter().collect::<String>(), "000N88V36");
+        assert_eq!(Figi::from_body(['B', 'B'], &body)?, figi);
+
+        let mut bad = body;
+        bad[8] = '7';
+        assert!(matches!(
+            Figi::from_body(['B', 'B'], &bad),
+            Err(InvalidFigi::Checksum(_))
+        ));
+        assert!(matches!(
+            Figi::from_body(['B', 'S'], &body),
+            Err(InvalidFigi::FirstTwo(_))
+        ));
+        Ok(())
+    }
+
+    #[cfg(feature = "tracing")]
+    #[tracing_test::traced_test]
+    #[test]
+    fn test_trace_invalid() {
+        assert!("BBG000N88V37".parse::<Figi>().is_err());
+        assert!(logs_contain("invalid FIGI"));
+        assert!(logs_contain("variant=Checksum"));
+        assert!(logs_contain("input=\"BBG000N88V37\""));
+
+        let long = "X".repeat(100);
+        assert!(long.parse::<Figi>().is_err());
+        assert!(logs_contain("variant=Length"));
+        assert!(!logs_contain(&long));
+    }
+
+    #[test]
+    fn test_prefix_precedes_third() {
+        for s in [
+            "GGG000N88V36",
+            "GBG000N88V36",
+            "GHG000N88V36",
+            "GGX000N88V36",
+        ] {
+            assert!(
+                matches!(s.parse::<Figi>(), Err(InvalidFigi::FirstTwo(_))),
+                "{s}"
+            );
+        }
+        assert!(matches!(
+            "BBX000N88V36".parse::<Figi>(),
+            Err(InvalidFigi::Third(_))
+        ));
+    }
+
+    #[test]
+    fn test_from_str_ocr() -> Result<(), InvalidFigi> {
+        let aapl = "BBG000N88V36".parse::<Figi>()?;
+        assert_eq!(Figi::from_str_ocr("BBG000N88V36"), Ok(aapl));
+        // 'O' for '0' resolves uniquely
+        assert_eq!(Figi::from_str_ocr("BBGOOON88V36"), Ok(aapl));
+
+        // Reading '8'/'B' either way is valid here
+        let tsm = "BBG000BD8ZK0".parse::<Figi>()?;
+        match Figi::from_str_ocr("BBGOOOBD8ZK0") {
+            Err(InvalidOcrFigi::Ambiguous(candidates)) => {
+                assert_eq!(candidates.len(), 2);
+                assert!(candidates.contains(&tsm));
+            }
+            other => panic!("expected ambiguous, got {other:?}"),
+        }
+
+        assert!(matches!(
+            Figi::from_str_ocr("BBG0005S8V36"),
+            Err(InvalidOcrFigi::NoCandidate(InvalidFigi::Checksum(_)))
+        ));
+        assert!(matches!(
+            Figi::from_str_ocr("BBGOOON88V3"),
+            Err(InvalidOcrFigi::NoCandidate(InvalidFigi::Length(_)))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_figi_is_copy() -> Result<(), InvalidFigi> {
+        fn packed(figi: Figi) -> PackedFigi {
+            figi.pack()
+        }
+        let figi = "BBG000N88V36".parse::<Figi>()?;
+        let p = packed(figi);
+        assert_eq!(p.unpack(), figi);
+        assert_eq!(packed(p.unpack()), p);
+        Ok(())
+    }
+
+    #[test]
+    fn test_with_computed_check_digit() -> Result<(), InvalidFigi> {
+        let body: [char; 11] = ['B', 'B', 'G', '0', '0', '0', 'N', '8', '8', 'V', '3'];
+        let figi = Figi::with_computed_check_digit(&body)?;
+        assert_eq!(String::from(&figi), "BBG000N88V36");
+
+        let mut reserved = body;
+        reserved[1] = 'S';
+        assert!(matches!(
+            Figi::with_computed_check_digit(&reserved),
+            Err(InvalidFigi::FirstTwo(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_issuing_agency() -> Result<(), InvalidFigi> {
+        let aapl = "BBG000N88V36".parse::<Figi>()?;
+        assert_eq!(aapl.issuing_agency(), Some("Bloomberg"));
+        let unknown = Figi::with_computed_check_digit(&[
+            'X', 'C', 'G', '0', '0', 'G', 'F', 'X', 'X', 'M', 'R',
+        ])?;
+        assert_eq!(unknown.issuing_agency(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_par_parse_many() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut inputs: Vec<String> = Vec::new();
+        for _ in 0..1_000 {
+            let mut s: String = random_body(&mut rng).iter().collect();
+            s.push('0');
+            inputs.push(s);
+        }
+        inputs.extend(["BBG000N88V36".into(), "BBG000N88V3".into(), String::new()]);
+        let inputs: Vec<&str> = inputs.iter().map(String::as_str).collect();
+        let sequential: Vec<_> = inputs.iter().map(|s| s.parse::<Figi>()).collect();
+        assert_eq!(Figi::par_parse_many(&inputs), sequential);
+    }
+
+    #[test]
+    fn test_from_chars_never_panics() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let alphabet: Vec<char> = ('\0'..='\u{7f}')
+            .chain(['Б', '嗨', '😀', '\u{200D}', '\u{FFFF}', char::MAX])
+            .collect();
+        for _ in 0..10_000 {
+            let mut s = [' '; 12];
+            for c in &mut s {
+                *c = *alphabet.choose(&mut rng).unwrap();
+            }
+            let result = std::panic::catch_unwind(|| Figi::from_chars(&s));
+            assert!(result.is_ok(), "{s:?}");
+        }
+        for _ in 0..10_000 {
+            let mut s = ['0'; 12];
+            s[..11].copy_from_slice(&random_body(&mut rng));
+            s[11] = *FIGI_ALPHABET.choose(&mut rng).unwrap();
+            let result = std::panic::catch_unwind(|| Figi::from_chars(&s));
+            assert!(result.is_ok(), "{s:?}");
+        }
+    }
+
+    #[test]
+    fn test_packed_try_from_bytes() -> Result<(), InvalidFigi> {
+        let figi = "BBG000N88V36".parse::<Figi>()?;
+        let bytes = figi.pack().to_bytes();
+        assert_eq!(PackedFigi::try_from(bytes)?.unpack(), figi);
+
+        let mut bad_checksum = bytes;
+        bad_checksum[7] ^= 0x1;
+        assert!(matches!(
+            PackedFigi::try_from(bad_checksum),
+            Err(InvalidFigi::Checksum(_))
+        ));
+        assert!(PackedFigi::try_from([0xFF; 8]).is_err());
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() -> Result<(), InvalidFigi> {
+        let figi = "BBG000N88V36".parse::<Figi>()?;
+
+        let json = serde_json::to_string(&figi).unwrap();
+        assert_eq!(json, "\"BBG000N88V36\"");
+        assert_eq!(serde_json::from_str::<Figi>(&json).unwrap(), figi);
+        assert!(serde_json::from_str::<Figi>("\"BBG000N88V37\"").is_err());
+
+        let packed = bincode::serialize(&figi).unwrap();
+        assert_eq!(packed[packed.len() - 8..], figi.pack().to_bytes());
+        assert_eq!(bincode::deserialize::<Figi>(&packed).unwrap(), figi);
+        assert!(
+            bincode::deserialize::<Figi>(&bincode::serialize(&[0xFFu8; 8][..]).unwrap()).is_err()
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "sqlx")]
+    #[tokio::test]
+    async fn test_sqlx_round_trip() -> Result<(), sqlx::Error> {
+        use sqlx::Connection;
+
+        let mut conn = sqlx::SqliteConnection::connect("sqlite::memory:").await?;
+        sqlx::query("CREATE TABLE figis (figi TEXT NOT NULL)")
+            .execute(&mut conn)
+            .await?;
+        let figi = "BBG000N88V36".parse::<Figi>().unwrap();
+        sqlx::query("INSERT INTO figis (figi) VALUES (?)")
+            .bind(figi)
+            .execute(&mut conn)
+            .await?;
+        let (stored,): (String,) = sqlx::query_as("SELECT figi FROM figis")
+            .fetch_one(&mut conn)
+            .await?;
+        assert_eq!(stored, "BBG000N88V36");
+        let (decoded,): (Figi,) = sqlx::query_as("SELECT figi FROM figis")
+            .fetch_one(&mut conn)
+            .await?;
+        assert_eq!(decoded, figi);
+
+        let invalid = sqlx::query_as::<_, (Figi,)>("SELECT 'BBG000N88V37'")
+            .fetch_one(&mut conn)
+            .await;
+        assert!(matches!(invalid, Err(sqlx::Error::ColumnDecode { .. })));
+        Ok(())
+    }
+
+    #[cfg(feature = "rkyv")]
+    #[test]
+    fn test_rkyv_round_trip() -> Result<(), rkyv::rancor::Error> {
+        use rkyv::rancor::Error;
+        use rkyv::vec::ArchivedVec;
+
+        let figis = ["BBG000N88V36", "BBG000BD8ZK0"].map(|s| s.parse::<Figi>().unwrap());
+        let bytes = rkyv::to_bytes::<Error>(&figis.to_vec())?;
+        let archived = rkyv::access::<ArchivedVec<ArchivedFigi>, Error>(&bytes)?;
+        assert_eq!(archived[0].as_bytes(), b"BBG000N88V36");
+        assert_eq!(archived[1].to_figi(), Ok(figis[1]));
+        assert_eq!(rkyv::deserialize::<Vec<Figi>, Error>(archived)?, figis);
+
+        // Access trusts the archive; deserializing validates it
+        let mut bytes = rkyv::to_bytes::<Error>(&figis[0])?;
+        let pos = bytes.len() - 1;
+        bytes[pos] = b'7';
+        let archived = rkyv::access::<ArchivedFigi, Error>(&bytes)?;
+        assert_eq!(archived.as_bytes(), b"BBG000N88V37");
+        assert!(rkyv::deserialize::<Figi, Error>(archived).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_figi_pattern() -> Result<(), InvalidFigi> {
+        let pattern: FigiPattern = "BBG000*".parse()?;
+        assert!(pattern.matches(&"BBG000N88V36".parse()?));
+        assert!(!pattern.matches(&"XCG00GFXXMR5".parse()?));
+
+        assert!("*"
+            .parse::<FigiPattern>()?
+            .matches(&"XCG00GFXXMR5".parse()?));
+        let exact: FigiPattern = "BBG000N88V36".parse()?;
+        assert!(exact.matches(&"BBG000N88V36".parse()?));
+        assert!(!exact.matches(&"BBG000BLNNH6".parse()?));
+
+        let err = |s: &str| s.parse::<FigiPattern>().unwrap_err();
+        assert!(matches!(err("BA*"), InvalidFigi::Consonant(_)));
+        assert!(matches!(err("BS*"), InvalidFigi::FirstTwo(_)));
+        assert!(matches!(err("BBX*"), InvalidFigi::Third(_)));
+        assert!(matches!(err("BBG00a*"), InvalidFigi::ConsonantOrNumeric(_)));
+        assert!(matches!(err("BBG000N88V36*"), InvalidFigi::Length(_)));
+        assert!(matches!(err("BBG000"), InvalidFigi::Length(_)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_hamming_distance() -> Result<(), InvalidFigi> {
+        let aapl = "BBG000N88V36".parse::<Figi>()?;
+        assert_eq!(aapl.hamming_distance(&aapl), 0);
+        // `3` and `C` (value 12) have the same digit sum at an undoubled
+        // position, so swapping them keeps the check digit.
+        let near = "BBG000N88VC6".parse::<Figi>()?;
+        assert_eq!(aapl.hamming_distance(&near), 1);
+        assert_eq!(near.hamming_distance(&aapl), 1);
+        assert_eq!(aapl.hamming_distance(&"BBG000BD8ZK0".parse()?), 5);
+        Ok(())
+    }
+
+    #[test]
+    fn test_classify() -> Result<(), InvalidFigi> {
+        let aapl = "BBG000N88V36".parse::<Figi>()?;
+        assert_eq!(classify("BBG000N88V36"), FigiStatus::Valid(aapl));
+        assert_eq!(
+            classify("BBG000N88V30"),
+            FigiStatus::ChecksumOnly { corrected: aapl }
+        );
+        // A letter in the check digit position is still only a checksum error.
+        assert_eq!(
+            classify("BBG000N88V3X"),
+            FigiStatus::ChecksumOnly { corrected: aapl }
+        );
+        assert!(matches!(
+            classify("BSG000N88V36"),
+            FigiStatus::Structural(InvalidFigi::FirstTwo(_))
+        ));
+        assert!(matches!(
+            classify("BBG000N88V3"),
+            FigiStatus::Structural(InvalidFigi::Length(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_display() -> Result<(), InvalidFigi> {
+        let figi = "BBG000N88V36".parse::<Figi>()?;
+        assert_eq!(format!("{figi}"), "BBG000N88V36");
+        assert_eq!(figi.to_string(), String::from(&figi));
+        assert_eq!(format!("{figi:#}"), "BBG-000N8-8V36");
+        assert_eq!(format!("{figi:>14}"), "  BBG000N88V36");
+        assert_eq!(format!("{figi:<#16}|"), "BBG-000N8-8V36  |");
+        Ok(())
+    }
+
+    #[test]
+    fn test_debug() -> Result<(), InvalidFigi> {
+        let figi = "BBG000N88V36".parse::<Figi>()?;
+        let expected = if cfg!(feature = "redact-debug") {
+            "Figi(\"BBG*******36\")"
+        } else {
+            "Figi(\"BBG000N88V36\")"
+        };
+        assert_eq!(format!("{figi:?}"), expected);
+        assert_eq!(format!("{:?}", Some(figi)), format!("Some({expected})"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_list() -> Result<(), InvalidFigi> {
+        let expected = [
+            "BBG000N88V36",
+            "BBG000BD8ZK0",
+            "XCG00GFXXMR5",
+            "BBG000BLNNH6",
+        ]
+        .map(|s| s.parse::<Figi>())
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(
+            parse_list(" BBG000N88V36,BBG000BD8ZK0\tXCG00GFXXMR5 ,, \n BBG000BLNNH6,"),
+            Ok(expected)
+        );
+        assert_eq!(parse_list(""), Ok(Vec::new()));
+        assert_eq!(parse_list(" , "), Ok(Vec::new()));
+
+        assert_eq!(
+            parse_list("BBG000N88V36,, BBG000BD8ZK0 XCG00GFXXMR3 BBG000N88V3"),
+            Err((2, InvalidFigi::Checksum("XCG00GFXXMR3".to_owned())))
+        );
+        assert!(matches!(
+            parse_list("BBG000N88V3, BBG000N88V36"),
+            Err((0, InvalidFigi::Length(_)))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_canonical() -> Result<(), WriteCanonicalError> {
+        let mut out = String::new();
+        for input in ["BBG000N88V36", " bbg000n88v36\t", "\nBbG000bd8Zk0 "] {
+            write_canonical(input, &mut out)?;
+            out.push(',');
+        }
+        assert_eq!(out, "BBG000N88V36,BBG000N88V36,BBG000BD8ZK0,");
+
+        let mut out = String::new();
+        assert_eq!(
+            write_canonical(" bbg000n88v37 ", &mut out),
+            Err(WriteCanonicalError::Invalid(InvalidFigi::Checksum(
+                "BBG000N88V37".to_owned()
+            )))
+        );
+        assert!(matches!(
+            write_canonical("BBG000N88V3", &mut out),
+            Err(WriteCanonicalError::Invalid(InvalidFigi::Length(_)))
+        ));
+        assert!(matches!(
+            write_canonical("\u{412}BG000N88V3", &mut out),
+            Err(WriteCanonicalError::Invalid(InvalidFigi::Confusable {
+                position: 1,
+                ..
+            }))
+        ));
+        assert!(out.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_canonical_write_error() {
+        /// Holds at most 8 bytes, like a stack buffer.
+        struct Fixed(String);
+        impl std::fmt::Write for Fixed {
+            fn write_str(&mut self, s: &str) -> std::fmt::Result {
+                if self.0.len() + s.len() > 8 {
+                    return Err(std::fmt::Error);
+                }
+                self.0.push_str(s);
+                Ok(())
+            }
+        }
+
+        let mut out = Fixed(String::new());
+        assert_eq!(
+            write_canonical("BBG000N88V36", &mut out),
+            Err(WriteCanonicalError::Write(std::fmt::Error))
+        );
+        assert_eq!(out.0, "BBG000N8");
+    }
+
+    #[test]
+    fn test_validate_report() {
+        let mut inputs = vec!["BBG000N88V36"; 5];
+        inputs.extend([
+            "BBG000N88V30",
+            "BBG000N88V31",
+            "BBG000N88V32",
+            "BBG000N88V33",
+        ]);
+        inputs.extend([
+            "BSG000N88V36",
+            "BBX000N88V36",
+            "BAG000N88V36",
+            "BBG000N88A36",
+        ]);
+        inputs.extend(["BBG000N88V3", "", "\u{412}BG000N88V36"]);
+        let report = validate_report(inputs.iter().copied());
+
+        let tally = |kind| report.tally(kind);
+        assert_eq!(report.valid, 5);
+        assert_eq!(tally(InvalidFigiKind::Checksum).count, 4);
+        assert_eq!(
+            tally(InvalidFigiKind::Checksum).samples,
+            ["BBG000N88V30", "BBG000N88V31", "BBG000N88V32"]
+        );
+        assert_eq!(tally(InvalidFigiKind::FirstTwo).count, 1);
+        assert_eq!(tally(InvalidFigiKind::Third).count, 1);
+        assert_eq!(tally(InvalidFigiKind::Consonant).count, 1);
+        assert_eq!(tally(InvalidFigiKind::ConsonantOrNumeric).count, 1);
+        assert_eq!(tally(InvalidFigiKind::Length).count, 2);
+        assert_eq!(tally(InvalidFigiKind::Length).samples, ["BBG000N88V3", ""]);
+        assert_eq!(
+            tally(InvalidFigiKind::Confusable).samples,
+            ["\u{412}BG000N88V36"]
+        );
+        assert_eq!(report.valid + report.invalid(), inputs.len());
+        assert_eq!(validate_report(std::iter::empty()), FigiReport::default());
+    }
+
+    #[test]
+    fn test_write_read_round_trip() -> std::io::Result<()> {
+        use std::io::{Cursor, ErrorKind};
+
+        let figis = ["BBG000N88V36", "BBG000BD8ZK0"].map(|s| s.parse::<Figi>().unwrap());
+        let mut cursor = Cursor::new(Vec::new());
+        for figi in &figis {
+            figi.write_to(&mut cursor)?;
+        }
+        assert_eq!(cursor.get_ref(), b"BBG000N88V36BBG000BD8ZK0");
+
+        cursor.set_position(0);
+        for figi in &figis {
+            assert_eq!(&Figi::read_from(&mut cursor)?, figi);
+        }
+        let eof = Figi::read_from(&mut cursor).unwrap_err();
+        assert_eq!(eof.kind(), ErrorKind::UnexpectedEof);
+
+        let invalid = Figi::read_from(&mut Cursor::new(b"BBG000N88V37")).unwrap_err();
+        assert_eq!(invalid.kind(), ErrorKind::InvalidData);
+        assert!(matches!(
+            invalid.into_inner().unwrap().downcast_ref::<InvalidFigi>(),
+            Some(InvalidFigi::Checksum(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_validated_unchecked() -> Result<(), InvalidFigi> {
+        for s in ["BBG000N88V36", "BBG000BD8ZK0", "XCG00GFXXMR5"] {
+            let checked = s.parse::<Figi>()?;
+            let bytes: [u8; 12] = s.as_bytes().try_into().unwrap();
+            // SAFETY: `bytes` was just validated by `parse`.
+            let unchecked = unsafe { Figi::from_validated_unchecked(bytes) };
+            assert_eq!(unchecked, checked);
+        }
+        Ok(())
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "invalid FIGI")]
+    fn test_from_validated_unchecked_checks_in_debug() {
+        // SAFETY: not upheld on purpose; debug builds panic before any
+        // transmute.
+        let _ = unsafe { Figi::from_validated_unchecked(*b"BBG000N88V37") };
+    }
+
+    #[test]
+    fn test_new_accepts_string_like() -> Result<(), InvalidFigi> {
+        let expected = "BBG000N88V36".parse::<Figi>()?;
+        let owned = String::from("BBG000N88V36");
+        assert_eq!(Figi::new("BBG000N88V36")?, expected);
+        assert_eq!(Figi::new(&owned)?, expected);
+        assert_eq!(Figi::new(Box::<str>::from("BBG000N88V36"))?, expected);
+        assert_eq!(Figi::new(owned)?, expected);
+        assert!(matches!(
+            Figi::new("BBG000N88V37"),
+            Err(InvalidFigi::Checksum(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_confusable() {
+        let err = "\u{412}\u{412}G000BLNNH6".parse::<Figi>().unwrap_err();
+        assert_eq!(
+            err,
+            InvalidFigi::Confusable {
+                input: String::from("\u{412}\u{412}G000BLNNH6"),
+                position: 1,
+                found: '\u{412}'
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "Invalid FIGI. Found Cyrillic \u{412}, expected Latin B at position 1 of \u{412}\u{412}G000BLNNH6."
+        );
+
+        let err = "BBG000BLNN\u{397}6".parse::<Figi>().unwrap_err();
+        assert_eq!(
+            err,
+            InvalidFigi::Confusable {
+                input: String::from("BBG000BLNN\u{397}6"),
+                position: 11,
+                found: '\u{397}'
+            }
+        );
+
+        // The winnow suite's Cyrillic example uses Б, which does not imitate a
+        // Latin letter, so it is still reported by length.
+        assert!(matches!(
+            "\u{411}\u{411}G000BLNNH6".parse::<Figi>(),
+            Err(InvalidFigi::Length(_))
+        ));
+        assert!(matches!(
+            "BBG\u{1F600}00BLNNH6".parse::<Figi>(),
+            Err(InvalidFigi::Length(_))
+        ));
+    }
+
+    #[test]
+    fn test_error_precedence() {
+        // Each input breaks every rule from the expected one onwards.
+        type Variant = fn(String) -> InvalidFigi;
+        let cases: [(&str, Variant); 5] = [
+            ("BSXAAAAAAAAX", InvalidFigi::FirstTwo),
+            ("BAXAAAAAAAAX", InvalidFigi::Consonant),
+            ("BBXAAAAAAAAX", InvalidFigi::Third),
+            ("BBGAAAAAAAAX", InvalidFigi::ConsonantOrNumeric),
+            ("BBG000N88V3X", InvalidFigi::Checksum),
+        ];
+        for (input, expected) in cases {
+            let expected = expected(input.to_owned());
+            let chars: [char; 12] = std::array::from_fn(|i| input.as_bytes()[i] as char);
+            assert_eq!(input.parse::<Figi>().unwrap_err(), expected);
+            assert_eq!(Figi::from_chars(&chars).unwrap_err(), expected);
+            assert_eq!(
+                crate::figi::validate_bytes(input.as_bytes()).unwrap_err(),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_enumerate_bodies() {
+        let figis: Vec<Figi> = Figi::enumerate_bodies(['B', 'B']).take(100).collect();
+        assert_eq!(figis.len(), 100);
+        for figi in &figis {
+            let s = String::from(figi);
+            assert!(s.starts_with("BBG000000"));
+            assert_eq!(s.parse::<Figi>().as_ref(), Ok(figi));
+        }
+        assert!(figis.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(String::from(&figis[0]), "BBG000000007");
+
+        assert_eq!(Figi::enumerate_bodies(['B', 'S']).count(), 0);
+        assert_eq!(Figi::enumerate_bodies(['A', 'B']).count(), 0);
+    }
+
+    #[test]
+    fn test_hash_agrees_with_eq() -> Result<(), InvalidFigi> {
+        use std::collections::HashSet;
+        use std::hash::{BuildHasher, RandomState};
+
+        let state = RandomState::new();
+        let a = "BBG000N88V36".parse::<Figi>()?;
+        let b = Figi::new(String::from("BBG000N88V36"))?;
+        assert_eq!(state.hash_one(a), state.hash_one(b));
+
+        let figis: HashSet<Figi> = Figi::enumerate_bodies(['B', 'B']).take(1000).collect();
+        assert_eq!(figis.len(), 1000);
+        assert!(figis.contains(&"BBG000000007".parse()?));
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_digit_weighting_parity() -> Result<(), InvalidFigi> {
+        // The standard doubles the value at every even position (2, 4, ..., 10)
+        // and sums the digits of each product. `BBG` contributes
+        // 1+1 (B=11) + 2+2 (2*11) + 1+6 (G=16) = 13, so `BBG00000000` checks
+        // to 7. A Z (35) adds 7+0 (2*35) at an even position but only 3+5 at
+        // an odd one, so the expected digits below only hold if the parity is
+        // aligned with the standard.
+        let cases = [
+            ("BBG00000000", '7'),
+            ("BBGZ0000000", '0'), // position 4, doubled: 13 + 7 = 20
+            ("BBG0Z000000", '9'), // position 5, single: 13 + 8 = 21
+            ("BBG000000Z0", '0'), // position 10, doubled
+            ("BBG0000000Z", '9'), // position 11, single
+            ("BZG00000000", '4'), // position 2, doubled: 2 + 7 + 7 = 16
+            ("ZBG00000000", '1'), // position 1, single: 8 + 4 + 7 = 19
+        ];
+        for (body, check) in cases {
+            let chars: Vec<char> = body.chars().collect();
+            let figi = Figi::with_computed_check_digit(&chars.try_into().unwrap())?;
+            assert_eq!(String::from(&figi), format!("{body}{check}"));
+            assert!(format!("{body}{check}").parse::<Figi>().is_ok());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_ascii_bytes_round_trip() -> Result<(), InvalidFigi> {
+        for figi in Figi::enumerate_bodies(['K', 'K']).step_by(7919).take(50) {
+            let bytes = figi.to_ascii_bytes();
+            assert_eq!(bytes, String::from(&figi).as_bytes());
+            assert_eq!(Figi::try_from(bytes)?, figi);
+        }
+        assert!(matches!(
+            Figi::try_from(*b"BBG000N88V37"),
+            Err(InvalidFigi::Checksum(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_ord_matches_string_order() -> Result<(), InvalidFigi> {
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut strings: Vec<String> = (0..500)
+            .map(|_| {
+                String::from(&Figi::with_computed_check_digit(&random_body(&mut rng)).unwrap())
+            })
+            .chain(
+                Figi::enumerate_bodies(['B', 'B'])
+                    .take(100)
+                    .map(|f| String::from(&f)),
+            )
+            .collect();
+        strings.extend(["BBG000N88V36", "XCG00GFXXMR5", "BCG000000005"].map(String::from));
+        strings.sort();
+        strings.dedup();
+        let mut figis: Vec<Figi> = strings
+            .iter()
+            .map(|s| s.parse())
+            .collect::<Result<_, _>>()?;
+
+        figis.shuffle(&mut rng);
+        figis.sort();
+        assert!(figis.iter().map(String::from).eq(strings.iter().cloned()));
+
+        for (i, s) in strings.iter().enumerate() {
+            assert_eq!(figis.binary_search(&s.parse()?), Ok(i));
+        }
+        let missing = "BBG000BD8ZK0".parse::<Figi>()?;
+        let at = figis.binary_search(&missing).unwrap_err();
+        assert_eq!(at, strings.partition_point(|s| s.as_str() < "BBG000BD8ZK0"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_masked() -> Result<(), InvalidFigi> {
+        let figi = "BBG000N88V36".parse::<Figi>()?;
+        assert_eq!(figi.masked(), "BBG*******36");
+        assert_eq!(figi.masked_with(6, 2), "BBG000****36");
+        assert_eq!(figi.masked_with(0, 0), "************");
+        assert_eq!(figi.masked_with(6, 6), "BBG000N88V36");
+        assert_eq!(figi.masked_with(20, 20), "BBG000N88V36");
+        Ok(())
+    }
+
+    #[test]
+    fn test_reserved_prefix_table() {
+        for c1 in 'A'..='Z' {
+            for c2 in 'A'..='Z' {
+                assert_eq!(
+                    is_reserved_prefix(c1, c2),
+                    reserved_prefix_match(c1, c2),
+                    "{c1}{c2}"
+                );
+            }
+        }
+        assert_eq!(
+            RESERVED_PREFIXES.iter().flatten().filter(|r| **r).count(),
+            7
+        );
+        assert!(!is_reserved_prefix('b', 's'));
+        assert!(!is_reserved_prefix('\u{412}', 'S'));
+    }
+
+    #[test]
+    fn test_prefix3() -> Result<(), InvalidFigi> {
+        assert_eq!("BBG000N88V36".parse::<Figi>()?.prefix3(), ['B', 'B', 'G']);
+        assert_eq!("XCG00GFXXMR5".parse::<Figi>()?.prefix3(), ['X', 'C', 'G']);
+        Ok(())
+    }
+
+    #[test]
+    fn test_placeholder() -> Result<(), InvalidFigi> {
+        let placeholder = Figi::placeholder();
+        assert_eq!(String::from(&placeholder), "ZZG000000008");
+        assert_eq!("ZZG000000008".parse::<Figi>()?, placeholder);
+        assert!(placeholder.is_valid());
+        assert!(placeholder.is_placeholder());
+        assert!(!"BBG000N88V36".parse::<Figi>()?.is_placeholder());
+        assert_eq!(placeholder.issuing_agency(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_stable_hash64() -> Result<(), InvalidFigi> {
+        // Golden values; changing them breaks every stored key
+        assert_eq!(
+            "BBG000N88V36".parse::<Figi>()?.stable_hash64(),
+            0x743e_bf1f_deb8_afbb
+        );
+        assert_eq!(
+            "BBG000BLNNH6".parse::<Figi>()?.stable_hash64(),
+            0x6581_1f49_2f39_1a0a
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_line() -> Result<(), InvalidFigi> {
+        let aapl = "BBG000N88V36".parse::<Figi>()?;
+        assert_eq!(Figi::from_line("BBG000N88V36")?, aapl);
+        assert_eq!(Figi::from_line("BBG000N88V36\n")?, aapl);
+        assert_eq!(Figi::from_line("BBG000N88V36\r\n")?, aapl);
+        // Only one line ending, and nothing else, is removed
+        for s in [
+            "BBG000N88V36\n\n",
+            "BBG000N88V36\r\n\r\n",
+            "BBG000N88V36\r",
+            "BBG000N88V36 \n",
+            "\nBBG000N88V36",
+            " BBG000N88V36\n",
+        ] {
+            assert!(
+                matches!(Figi::from_line(s), Err(InvalidFigi::Length(_))),
+                "{s:?}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_repair_candidates() -> Result<(), InvalidFigi> {
+        let aapl = "BBG000N88V36".parse::<Figi>()?;
+        for near in [
+            "BBG000N88V37",
+            "BBG000N89V36",
+            "BBG0Z0N88V36",
+            "CBG000N88V36",
+        ] {
+            let candidates = Figi::repair_candidates(near);
+            assert!(candidates.contains(&aapl), "{near}");
+            for figi in &candidates {
+                let changed = String::from(figi)
+                    .chars()
+                    .zip(near.chars())
+                    .filter(|(a, b)| a != b)
+                    .count();
+                assert_eq!(changed, 1, "{near} -> {figi}");
+            }
+        }
+        // A non-ASCII character can be replaced too
+        assert!(Figi::repair_candidates("BBG000N88\u{412}36").contains(&aapl));
+        assert!(!Figi::repair_candidates("BBG000N88V36").contains(&aapl));
+        assert!(Figi::repair_candidates("BBG000N88V3").is_empty());
+        assert!(Figi::repair_candidates("BBG000N88V366").is_empty());
+
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut longest = 0;
+        for _ in 0..1_000 {
+            let mut s: String = random_body(&mut rng).iter().collect();
+            s.push(char::from(*b"0123456789".choose(&mut rng).unwrap()));
+            longest = longest.max(Figi::repair_candidates(&s).len());
+        }
+        assert!(longest <= MAX_REPAIR_CANDIDATES, "{longest}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_figi_kind() {
+        for (i, kind) in InvalidFigiKind::ALL.into_iter().enumerate() {
+            assert_eq!(kind.index(), i);
+        }
+        for (s, kind) in [
+            ("BBG000N88V37", InvalidFigiKind::Checksum),
+            ("BSG000N88V36", InvalidFigiKind::FirstTwo),
+            ("BBX000N88V36", InvalidFigiKind::Third),
+            ("BAG000N88V36", InvalidFigiKind::Consonant),
+            ("BBG000N88A36", InvalidFigiKind::ConsonantOrNumeric),
+            ("BBG000N88V3", InvalidFigiKind::Length),
+            ("\u{412}BG000N88V36", InvalidFigiKind::Confusable),
+        ] {
+            let err = s.parse::<Figi>().unwrap_err();
+            assert_eq!(err.kind(), kind, "{s}");
+            assert_eq!(err.input(), s);
+        }
+    }
+
+    #[test]
+    fn test_alphabet_matches_character_classes() {
+        let accepted = |class: fn(char) -> bool| ('\0'..='\u{7f}').filter(|c| class(*c)).collect();
+        let conso_numeric: Vec<char> = accepted(|c| ConsonantOrNumeric::try_from(c).is_ok());
+        let consonants: Vec<char> = accepted(|c| Consonant::try_from(c).is_ok());
+        assert_eq!(conso_numeric, FIGI_ALPHABET);
+        assert_eq!(consonants, CONSONANTS);
+        for c in FIGI_ALPHABET {
+            let discriminant = ConsonantOrNumeric::try_from(c).unwrap() as u8;
+            assert_eq!(check_digit_value(c), discriminant, "{c}");
+        }
+    }
+
+    #[test]
+    fn test_checksum_digit_value() -> Result<(), InvalidFigi> {
+        let aapl = "BBG000N88V36".parse::<Figi>()?;
+        assert_eq!(aapl.checksum_digit_value(), 6);
+        let tsm = "BBG000BD8ZK0".parse::<Figi>()?;
+        assert_eq!(tsm.checksum_digit_value(), 0);
+        Ok(())
+    }
+}
@@ -0,0 +1,134 @@
+//! Traits shared by all identifier types.
+
+use crate::{figi, figi_imperative, ibrk_figi};
+
+/// The kind of security identifier.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum IdKind {
+    /// A FIGI, whichever of [`figi::Figi`], [`figi_imperative::Figi`] or
+    /// [`ibrk_figi::Figi`] holds it.
+    Figi,
+}
+
+impl IdKind {
+    /// The length every identifier of this kind has, if it is fixed.
+    pub const fn fixed_len(self) -> Option<usize> {
+        match self {
+            IdKind::Figi => Some(12),
+        }
+    }
+}
+
+/// An identifier backed by its canonical string.
+///
+/// The length lives on [`IdKind::fixed_len`] rather than an associated constant
+/// so that the trait can be used as `&dyn Identifier`.
+///
+/// `ibrk_figi::Figi` is stored as enums rather than a string and so does not
+/// implement this trait.
+pub trait Identifier {
+    /// The canonical string form of the identifier.
+    fn as_str(&self) -> &str;
+
+    /// Which kind of identifier this is.
+    fn kind(&self) -> IdKind;
+
+    /// The canonical form as raw bytes.
+    fn as_bytes(&self) -> &[u8] {
+        self.as_str().as_bytes()
+    }
+}
+
+impl Identifier for figi::Figi {
+    fn as_str(&self) -> &str {
+        self
+    }
+
+    fn kind(&self) -> IdKind {
+        IdKind::Figi
+    }
+}
+
+impl Identifier for figi_imperative::Figi {
+    fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    fn kind(&self) -> IdKind {
+        IdKind::Figi
+    }
+}
+
+// The three FIGI types compare equal when their canonical strings match, so a
+// migration can assert that two parsers agree.
+
+impl PartialEq<figi_imperative::Figi> for figi::Figi {
+    fn eq(&self, other: &figi_imperative::Figi) -> bool {
+        **self == other.0
+    }
+}
+
+impl PartialEq<figi::Figi> for figi_imperative::Figi {
+    fn eq(&self, other: &figi::Figi) -> bool {
+        other == self
+    }
+}
+
+impl PartialEq<ibrk_figi::Figi> for figi::Figi {
+    fn eq(&self, other: &ibrk_figi::Figi) -> bool {
+        self.chars().eq(other.chars())
+    }
+}
+
+impl PartialEq<figi::Figi> for ibrk_figi::Figi {
+    fn eq(&self, other: &figi::Figi) -> bool {
+        other == self
+    }
+}
+
+impl PartialEq<ibrk_figi::Figi> for figi_imperative::Figi {
+    fn eq(&self, other: &ibrk_figi::Figi) -> bool {
+        self.0.chars().eq(other.chars())
+    }
+}
+
+impl PartialEq<figi_imperative::Figi> for ibrk_figi::Figi {
+    fn eq(&self, other: &figi_imperative::Figi) -> bool {
+        other == self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dyn_identifier() {
+        let winnow: figi::Figi = "BBG000BLNNH6".parse().unwrap();
+        let imperative: figi_imperative::Figi = "BBG000BLNNH6".parse().unwrap();
+        let ids: [&dyn Identifier; 2] = [&winnow, &imperative];
+        for id in ids {
+            assert_eq!(id.as_str(), "BBG000BLNNH6");
+            assert_eq!(id.as_bytes(), b"BBG000BLNNH6");
+            assert_eq!(id.kind(), IdKind::Figi);
+            assert_eq!(id.kind().fixed_len(), Some(id.as_str().len()));
+        }
+    }
+
+    #[test]
+    fn test_cross_type_eq() {
+        let winnow: figi::Figi = "BBG000BLNNH6".parse().unwrap();
+        let imperative: figi_imperative::Figi = "BBG000BLNNH6".parse().unwrap();
+        let ibrk: ibrk_figi::Figi = "BBG000BLNNH6".parse().unwrap();
+        assert_eq!(winnow, imperative);
+        assert_eq!(imperative, winnow);
+        assert_eq!(winnow, ibrk);
+        assert_eq!(ibrk, winnow);
+        assert_eq!(imperative, ibrk);
+        assert_eq!(ibrk, imperative);
+
+        let other: ibrk_figi::Figi = "BBG000N88V36".parse().unwrap();
+        assert_ne!(winnow, other);
+        assert_ne!(other, imperative);
+    }
+}
@@ -1,3 +1,38 @@
+//! Symbology for security identification.
+//!
+//! Fixed-size values are `Copy` and cheap to pass by value: [`ibrk_figi::Figi`],
+//! [`ibrk_figi::PackedFigi`], [`figi_dfa::FigiDfa`], [`figi::CertifiedProvider`],
+//! [`figi::CompactFigi`], [`identifier::IdKind`], [`bbunique::AssetClass`],
+//! [`price::Price`], [`red::RedCode`], [`scratch::Qty`] and its parse
+//! options, and the [`blpapi`] scheme and provider enums. The
+//! `String`-backed [`figi::Figi`], [`figi_imperative::Figi`] and
+//! [`bbunique::BbUnique`] are only `Clone`, as are the error types that carry
+//! the rejected input.
+//!
+//! The error enums are `#[non_exhaustive]`: new validation rules are added as
+//! new variants in minor releases, so a `match` on one outside this crate
+//! needs a wildcard arm. Within the crate, matches stay exhaustive so that a
+//! new variant must be handled everywhere it is reported.
+
+pub mod bbunique;
+pub mod blpapi;
+#[cfg(feature = "indexmap")]
+pub mod collections;
+pub mod error;
+#[cfg(feature = "ffi")]
+pub mod ffi;
 pub mod figi;
+pub mod figi_dfa;
+pub mod figi_group;
 pub mod figi_imperative;
 pub mod ibrk_figi;
+pub mod identifier;
+#[cfg(feature = "metrics")]
+pub mod metrics;
+#[cfg(feature = "polars")]
+pub mod polars_ext;
+pub mod price;
+#[cfg(feature = "proptest")]
+pub mod proptest_ext;
+pub mod red;
+pub mod scratch;
@@ -0,0 +1,117 @@
+//! Process-wide counters of batch parse outcomes, for capacity planning.
+//!
+//! [`Figi::par_parse_many`], [`parse_list`] and [`validate_report`] count every
+//! input they parse. Counters are relaxed atomics, so a [`FigiMetrics::snapshot`]
+//! taken during a batch may mix counts from before and after an input.
+//!
+//! [`Figi::par_parse_many`]: crate::ibrk_figi::Figi::par_parse_many
+//! [`parse_list`]: crate::ibrk_figi::parse_list
+//! [`validate_report`]: crate::ibrk_figi::validate_report
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::ibrk_figi::{Figi, InvalidFigi, InvalidFigiKind};
+
+static GLOBAL: FigiMetrics = FigiMetrics::new();
+
+#[derive(Debug, Default)]
+/// Lock-free parse counters.
+pub struct FigiMetrics {
+    parsed: AtomicU64,
+    failed: [AtomicU64; InvalidFigiKind::COUNT],
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+/// The counts in a [`FigiMetrics`] at one moment.
+pub struct MetricsSnapshot {
+    /// Inputs that parsed as a valid FIGI.
+    pub parsed: u64,
+    failed: [u64; InvalidFigiKind::COUNT],
+}
+
+impl MetricsSnapshot {
+    /// Inputs that failed to parse with errors of `kind`.
+    pub fn failed_with(&self, kind: InvalidFigiKind) -> u64 {
+        self.failed[kind.index()]
+    }
+
+    /// Inputs that failed to parse, over every [`InvalidFigiKind`].
+    pub fn failed(&self) -> u64 {
+        self.failed.iter().sum()
+    }
+
+    /// Every input counted, valid or not.
+    pub fn total(&self) -> u64 {
+        self.parsed + self.failed()
+    }
+}
+
+impl FigiMetrics {
+    const fn new() -> Self {
+        // A `const` item may be repeated in an array expression; the lint is
+        // about sharing one, which repetition does not do
+        #[allow(clippy::declare_interior_mutable_const)]
+        const ZERO: AtomicU64 = AtomicU64::new(0);
+        FigiMetrics {
+            parsed: ZERO,
+            failed: [ZERO; InvalidFigiKind::COUNT],
+        }
+    }
+
+    /// The counters the batch APIs update.
+    pub fn global() -> &'static FigiMetrics {
+        &GLOBAL
+    }
+
+    /// The current counts.
+    pub fn snapshot(&self) -> MetricsSnapshot {
+        let load = |c: &AtomicU64| c.load(Ordering::Relaxed);
+        let mut failed = [0; InvalidFigiKind::COUNT];
+        for (count, counter) in failed.iter_mut().zip(&self.failed) {
+            *count = load(counter);
+        }
+        MetricsSnapshot {
+            parsed: load(&self.parsed),
+            failed,
+        }
+    }
+
+    pub(crate) fn record(&self, result: &Result<Figi, InvalidFigi>) {
+        let counter = match result {
+            Ok(_) => &self.parsed,
+            Err(e) => &self.failed[e.kind().index()],
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record() {
+        // A local instance, so tests updating the global counters in
+        // parallel cannot interfere
+        let metrics = FigiMetrics::default();
+        for s in [
+            "BBG000N88V36",
+            "BBG000N88V36",
+            "BBG000N88V37",
+            "BBG000N88V3",
+        ] {
+            metrics.record(&s.parse());
+        }
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot.parsed, 2);
+        for kind in InvalidFigiKind::ALL {
+            let expected = match kind {
+                InvalidFigiKind::Checksum | InvalidFigiKind::Length => 1,
+                _ => 0,
+            };
+            assert_eq!(snapshot.failed_with(kind), expected, "{kind:?}");
+        }
+        assert_eq!(snapshot.failed(), 2);
+        assert_eq!(snapshot.total(), 4);
+    }
+}
@@ -0,0 +1,66 @@
+//! Validating FIGI columns in Polars, behind the `polars` feature.
+
+use ::polars::prelude::*;
+
+use crate::ibrk_figi::Figi;
+
+/// Validate a UTF8 column of FIGIs.
+///
+/// # Returns
+/// A boolean mask, named after `s`, that is `true` for each valid FIGI, and the
+/// number of rows that failed. Null rows count as failures.
+///
+/// # Errors
+/// Will error if `s` is not a string column.
+pub fn validate_figi_series(s: &Series) -> PolarsResult<(BooleanChunked, usize)> {
+    let mut failures = 0;
+    let mask: BooleanChunked = s
+        .str()?
+        .iter()
+        .map(|v| {
+            let valid = v.is_some_and(|v| v.parse::<Figi>().is_ok());
+            failures += usize::from(!valid);
+            valid
+        })
+        .collect();
+    Ok((mask.with_name(s.name().clone()), failures))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_figi_series() -> PolarsResult<()> {
+        let s = Series::new(
+            "figi".into(),
+            &[
+                Some("BBG000N88V36"),
+                Some("BBG000N88V37"),
+                None,
+                Some("BBG000BD8ZK0"),
+                Some("not a figi"),
+            ],
+        );
+        let (mask, failures) = validate_figi_series(&s)?;
+        let mask: Vec<Option<bool>> = mask.iter().collect();
+        assert_eq!(
+            mask,
+            [
+                Some(true),
+                Some(false),
+                Some(false),
+                Some(true),
+                Some(false)
+            ]
+        );
+        assert_eq!(failures, 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_non_string_series() {
+        let s = Series::new("figi".into(), &[1i32, 2, 3]);
+        assert!(validate_figi_series(&s).is_err());
+    }
+}
@@ -0,0 +1,125 @@
+//! Prices quoted in whole points and 32nds, as for US Treasury futures.
+//!
+//! `"110-16"` is 110 and 16/32. A third digit gives quarters of a 32nd using
+//! the usual shorthand: `0`, `2` (1/4), `5` (1/2) and `7` (3/4), with `+` as
+//! another way to write a half, so `"110-16+"` and `"110-165"` are both
+//! 110 and 16.5/32.
+
+use winnow::ascii::digit1;
+use winnow::combinator::{alt, opt, preceded};
+use winnow::prelude::*;
+use winnow::token::take_while;
+use winnow::PResult;
+
+/// A price in whole points, 32nds and quarters of a 32nd, kept exact.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Price {
+    whole: u64,
+    ticks: u8,
+    quarter_ticks: u8,
+}
+
+impl Price {
+    /// The price as a decimal, e.g. 110.515625 for `"110-16+"`.
+    pub fn value(&self) -> f64 {
+        self.whole as f64 + (f64::from(self.ticks) + f64::from(self.quarter_ticks) / 4.0) / 32.0
+    }
+
+    /// The whole points before the `-`.
+    pub fn whole(&self) -> u64 {
+        self.whole
+    }
+
+    /// The price after the whole points in 128ths: four per 32nd.
+    pub fn fraction_128ths(&self) -> u16 {
+        u16::from(self.ticks) * 4 + u16::from(self.quarter_ticks)
+    }
+}
+
+impl std::str::FromStr for Price {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        price.parse(s).map_err(|e| e.to_string())
+    }
+}
+
+/// Quarters of a 32nd written after the two tick digits.
+///
+/// # Example
+/// "" -> 0
+/// "2" -> 1
+/// "+" -> 2
+/// "5" -> 2
+/// "7" -> 3
+fn quarter_ticks(input: &mut &str) -> PResult<u8> {
+    opt(alt((
+        '0'.value(0),
+        '2'.value(1),
+        alt(('+', '5')).value(2),
+        '7'.value(3),
+    )))
+    .map(|q| q.unwrap_or(0))
+    .parse_next(input)
+}
+
+/// Parse a price in points and 32nds, such as `"110-16"`, `"110-162"` or
+/// `"110-16+"`. The two tick digits must be `00` through `31`.
+pub fn price(input: &mut &str) -> PResult<Price> {
+    let whole = digit1.parse_to().parse_next(input)?;
+    let ticks = preceded('-', take_while(2, |c: char| c.is_ascii_digit()))
+        .parse_to()
+        .verify(|t: &u8| *t < 32)
+        .parse_next(input)?;
+    let quarter_ticks = quarter_ticks.parse_next(input)?;
+    Ok(Price {
+        whole,
+        ticks,
+        quarter_ticks,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_thirty_seconds() -> Result<(), String> {
+        assert_eq!("110-16".parse::<Price>()?.value(), 110.5);
+        assert_eq!("110-160".parse::<Price>()?.value(), 110.5);
+        assert_eq!("110-00".parse::<Price>()?.value(), 110.0);
+        assert_eq!("99-31".parse::<Price>()?.value(), 99.0 + 31.0 / 32.0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_quarter_ticks() -> Result<(), String> {
+        assert_eq!("110-16+".parse::<Price>()?.value(), 110.515625);
+        assert_eq!("110-165".parse::<Price>()?, "110-16+".parse::<Price>()?);
+        assert_eq!("110-162".parse::<Price>()?.value(), 110.5078125);
+        assert_eq!("110-167".parse::<Price>()?.value(), 110.5234375);
+        assert_eq!("110-16+".parse::<Price>()?.fraction_128ths(), 66);
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid() {
+        for s in [
+            "", "110", "110-", "110-1", "110-32", "110-163", "110-16++", "110-16 ", "-16", "110.5",
+        ] {
+            assert!(s.parse::<Price>().is_err(), "{s:?}");
+        }
+    }
+
+    #[test]
+    fn test_ordering() -> Result<(), String> {
+        let mut prices = ["110-16+", "109-31", "110-16", "110-162"]
+            .map(|s| s.parse::<Price>())
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()?;
+        prices.sort();
+        let values: Vec<f64> = prices.iter().map(Price::value).collect();
+        assert!(values.windows(2).all(|w| w[0] < w[1]));
+        Ok(())
+    }
+}
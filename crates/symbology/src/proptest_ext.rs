@@ -0,0 +1,67 @@
+//! Proptest strategies for identifier strings, behind the `proptest` feature.
+//!
+//! FIGI is currently the only identifier type the crate ships.
+
+use ::proptest::prelude::*;
+use ::proptest::sample::select;
+
+use crate::ibrk_figi::{is_reserved_prefix, Figi, CONSONANTS, FIGI_ALPHABET};
+
+/// FIGIs with a valid structure and check digit.
+pub fn valid_figi() -> impl Strategy<Value = String> {
+    (
+        (select(CONSONANTS), select(CONSONANTS))
+            .prop_filter("reserved prefix", |&(c1, c2)| !is_reserved_prefix(c1, c2)),
+        [(); 8].map(|()| select(&FIGI_ALPHABET[..])),
+    )
+        .prop_map(|((c1, c2), rest)| {
+            let mut body = ['G'; 11];
+            body[0] = c1;
+            body[1] = c2;
+            body[3..].copy_from_slice(&rest);
+            let figi = Figi::with_computed_check_digit(&body).expect("body is structurally valid");
+            String::from(&figi)
+        })
+}
+
+/// FIGIs with a valid structure but the wrong check digit.
+pub fn invalid_figi() -> impl Strategy<Value = String> {
+    (valid_figi(), 1..=9u8).prop_map(|(mut s, offset)| {
+        let check_digit = s.pop().expect("a FIGI is 12 characters") as u8 - b'0';
+        s.push(char::from(b'0' + (check_digit + offset) % 10));
+        s
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ibrk_figi::InvalidFigi;
+
+    proptest! {
+        #[test]
+        fn valid_figi_parses(s in valid_figi()) {
+            let figi = s.parse::<Figi>().unwrap();
+            prop_assert_eq!(String::from(&figi), s.clone());
+            prop_assert!(s.parse::<crate::figi::Figi>().is_ok());
+            prop_assert!(s.parse::<crate::figi_imperative::Figi>().is_ok());
+        }
+
+        #[test]
+        fn exactly_one_check_digit_per_body(s in valid_figi()) {
+            let mut chars: [char; 12] = s.chars().collect::<Vec<_>>().try_into().unwrap();
+            let valid: Vec<char> = ('0'..='9')
+                .filter(|d| {
+                    chars[11] = *d;
+                    Figi::from_chars(&chars).is_ok()
+                })
+                .collect();
+            prop_assert_eq!(valid, vec![s.chars().last().unwrap()]);
+        }
+
+        #[test]
+        fn invalid_figi_fails_checksum(s in invalid_figi()) {
+            prop_assert!(matches!(s.parse::<Figi>(), Err(InvalidFigi::Checksum(_))));
+        }
+    }
+}
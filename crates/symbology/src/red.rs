@@ -0,0 +1,163 @@
+use std::fmt;
+use std::str::FromStr;
+use winnow::prelude::*;
+use winnow::token::take_while;
+
+use crate::figi::from_utf8_unchecked;
+
+/// A Markit RED code, the credit-market identifier for CDS reference entities
+/// and obligations: a 6-character entity code, or a 9-character pair code that
+/// extends the entity code with 3 characters for the reference obligation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RedCode {
+    /// A reference entity, e.g. `"2H6677"`.
+    Entity(RedEntity),
+    /// A reference entity and obligation, e.g. `"2H6677AA1"`.
+    Pair(RedPair),
+}
+
+/// A 6-character RED entity code. Only parsing builds one, so it always holds
+/// uppercase ASCII letters and digits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RedEntity([u8; 6]);
+
+/// A 9-character RED pair code: an entity code and 3 characters for the
+/// reference obligation. Only parsing builds one, so it always holds
+/// uppercase ASCII letters and digits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RedPair([u8; 9]);
+
+impl RedEntity {
+    /// The code as written.
+    pub fn as_str(&self) -> &str {
+        // SAFETY: only `red_code` builds a `RedEntity`, from ASCII
+        unsafe { from_utf8_unchecked(&self.0, "`red_code` only accepts ASCII") }
+    }
+}
+
+impl RedPair {
+    /// The code as written.
+    pub fn as_str(&self) -> &str {
+        // SAFETY: only `red_code` builds a `RedPair`, from ASCII
+        unsafe { from_utf8_unchecked(&self.0, "`red_code` only accepts ASCII") }
+    }
+
+    /// The entity code this pair code starts with.
+    pub fn entity(&self) -> RedEntity {
+        let [a, b, c, d, e, f, ..] = self.0;
+        RedEntity([a, b, c, d, e, f])
+    }
+}
+
+impl RedCode {
+    /// The code as written.
+    pub fn as_str(&self) -> &str {
+        match self {
+            RedCode::Entity(e) => e.as_str(),
+            RedCode::Pair(p) => p.as_str(),
+        }
+    }
+
+    /// The 6-character entity code, which a pair code starts with.
+    pub fn entity(&self) -> RedCode {
+        match self {
+            RedCode::Entity(_) => *self,
+            RedCode::Pair(p) => RedCode::Entity(p.entity()),
+        }
+    }
+}
+
+impl fmt::Display for RedCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for RedCode {
+    type Err = String;
+
+    /// Parse a 6-character entity code or a 9-character pair code. Only
+    /// uppercase ASCII letters and digits are allowed.
+    ///
+    /// # Example
+    /// ```
+    /// use symbology::red::RedCode;
+    ///
+    /// let pair: RedCode = "2H6677AA1".parse().unwrap();
+    /// assert!(matches!(pair, RedCode::Pair(_)));
+    /// assert_eq!(pair.entity().to_string(), "2H6677");
+    ///
+    /// assert!("2H667".parse::<RedCode>().is_err());
+    /// ```
+    ///
+    /// The payloads can only be built by parsing:
+    /// ```compile_fail
+    /// use symbology::red::{RedCode, RedEntity};
+    ///
+    /// let code = RedCode::Entity(RedEntity([0xFF; 6]));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        red_code.parse(s).map_err(|e| e.to_string())
+    }
+}
+
+fn red_code(i: &mut &str) -> PResult<RedCode> {
+    take_while(1.., |c: char| c.is_ascii_uppercase() || c.is_ascii_digit())
+        .verify_map(|s: &str| match s.len() {
+            6 => s
+                .as_bytes()
+                .try_into()
+                .ok()
+                .map(|b| RedCode::Entity(RedEntity(b))),
+            9 => s
+                .as_bytes()
+                .try_into()
+                .ok()
+                .map(|b| RedCode::Pair(RedPair(b))),
+            _ => None,
+        })
+        .parse_next(i)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_entity() {
+        let mut input = "2H6677";
+        assert_eq!(
+            red_code(&mut input),
+            Ok(RedCode::Entity(RedEntity(*b"2H6677")))
+        );
+        let code: RedCode = "2H6677".parse().unwrap();
+        assert_eq!(code.to_string(), "2H6677");
+        assert_eq!(code.entity(), code);
+    }
+
+    #[test]
+    fn test_pair() {
+        let code: RedCode = "2H6677AA1".parse().unwrap();
+        assert_eq!(code, RedCode::Pair(RedPair(*b"2H6677AA1")));
+        assert_eq!(code.as_str(), "2H6677AA1");
+        assert_eq!(code.entity(), RedCode::Entity(RedEntity(*b"2H6677")));
+        let RedCode::Pair(pair) = code else {
+            panic!("{code:?} is not a pair code");
+        };
+        assert_eq!(pair.entity().as_str(), "2H6677");
+    }
+
+    #[test]
+    fn test_invalid_length() {
+        for s in ["", "2H667", "2H6677A", "2H6677AA", "2H6677AA12"] {
+            assert!(s.parse::<RedCode>().is_err(), "{s:?}");
+        }
+    }
+
+    #[test]
+    fn test_invalid_characters() {
+        for s in ["2h6677", "2H66-7", "2H6677AA ", "2H667\u{412}"] {
+            assert!(s.parse::<RedCode>().is_err(), "{s:?}");
+        }
+    }
+}
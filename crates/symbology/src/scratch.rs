@@ -1,25 +1,159 @@
-use ::winnow::ascii::{digit0, digit1, multispace0 as ws};
-use winnow::combinator::{alt, delimited, opt, preceded, repeat, separated, terminated};
-use winnow::ascii::Caseless;
+use winnow::ascii::{digit1, Caseless};
+use winnow::combinator::{alt, delimited, not, opt, terminated};
 use winnow::prelude::*;
+use winnow::stream::AsChar;
 use winnow::token::*;
 use winnow::PResult;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Factor {
     M,
     MM,
     MMM,
     MMMM,
     P,
+    /// Basis points, hundredths of a percent.
+    Bps,
+    /// Percent.
+    Pct,
 }
 
-#[derive(Debug, PartialEq)]
+impl Factor {
+    /// The multiplier this factor applies to a quantity.
+    pub fn multiplier(&self, config: &ParseConfig) -> f64 {
+        match self {
+            Factor::M => 1e3,
+            Factor::MM => 1e6,
+            Factor::MMM => 1e9,
+            Factor::MMMM => 1e12,
+            Factor::P => match config.p_factor {
+                PFactor::Plain => 1.0,
+                PFactor::Divisor(d) => 1.0 / d,
+            },
+            Factor::Bps => 1e-4,
+            Factor::Pct => 1e-2,
+        }
+    }
+}
+
+/// Interpretation of the `P` factor.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PFactor {
+    /// `P` is "plain": the quantity passes through unchanged.
+    Plain,
+    /// `P` divides the quantity, e.g. `Divisor(100.0)` for pence (GBX) quoting.
+    Divisor(f64),
+}
+
+/// Which characters mark the decimal point and digit grouping.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum DecimalStyle {
+    /// `1,000.5`
+    #[default]
+    Us,
+    /// `1.000,5`
+    Eu,
+}
+
+impl DecimalStyle {
+    /// The `(decimal point, grouping)` characters for this style.
+    fn separators(self) -> (char, char) {
+        match self {
+            DecimalStyle::Us => ('.', ','),
+            DecimalStyle::Eu => (',', '.'),
+        }
+    }
+}
+
+/// Options controlling how quantities are parsed and scaled.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ParseConfig {
+    /// How the `P` factor scales a quantity; see [`PFactor`]. Defaults to
+    /// [`PFactor::Plain`].
+    pub p_factor: PFactor,
+    /// Which characters [`number`] accepts as the decimal point and digit
+    /// grouping: `.` and `,` for [`DecimalStyle::Us`], the default, or `,` and
+    /// `.` for [`DecimalStyle::Eu`].
+    pub decimal_style: DecimalStyle,
+    /// Reject a bare number with no factor, e.g. "100"; write "100P" for a
+    /// plain quantity instead. Off by default.
+    pub require_factor: bool,
+}
+
+impl Default for ParseConfig {
+    fn default() -> Self {
+        ParseConfig {
+            p_factor: PFactor::Plain,
+            decimal_style: DecimalStyle::Us,
+            require_factor: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Qty {
     value: f64,
     factor: Option<Factor>,
 }
 
+impl Qty {
+    /// Parse a quantity using the options in `config`.
+    pub fn parse_with(s: &str, config: &ParseConfig) -> Result<Self, String> {
+        parse_quantity_with(config)
+            .parse(s)
+            .map_err(|e| e.to_string())
+    }
+
+    /// The quantity with its factor applied, treating `P` as plain.
+    pub fn scaled(&self) -> f64 {
+        self.scaled_with(&ParseConfig::default())
+    }
+
+    /// Round the number as written, before its factor applies, to `decimals`
+    /// decimal places, with halves rounded away from zero: `"1.2345M"` rounds
+    /// to `1.23M` at 2 places.
+    ///
+    /// The result is still an `f64`, the nearest one to the rounded decimal,
+    /// so this cleans up accumulated error such as `0.1 + 0.2` but cannot make
+    /// every decimal exact. Halves are judged on the stored binary value:
+    /// `1.005` is slightly below one and a half hundredths, so it rounds to
+    /// `1.0`. Values with no fractional digits at this precision are
+    /// returned unchanged.
+    pub fn round_to(&self, decimals: u32) -> Qty {
+        let scale = 10f64.powi(i32::try_from(decimals).unwrap_or(i32::MAX));
+        let shifted = self.value * scale;
+        if !shifted.is_finite() || shifted.abs() >= 2f64.powi(52) {
+            return *self;
+        }
+        Qty {
+            value: shifted.round() / scale,
+            factor: self.factor,
+        }
+    }
+
+    /// The quantity with its factor applied as described by `config`.
+    pub fn scaled_with(&self, config: &ParseConfig) -> f64 {
+        // Divide rather than multiply by the reciprocal, so "50bps" is
+        // exactly 0.005
+        match &self.factor {
+            Some(Factor::Bps) => self.value / 1e4,
+            Some(Factor::Pct) => self.value / 1e2,
+            Some(factor) => self.value * factor.multiplier(config),
+            None => self.value,
+        }
+    }
+}
+
+/// A [`Qty`] together with the exact text it was parsed from, for audit trails.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedQty {
+    /// The parsed quantity.
+    pub qty: Qty,
+    /// Exactly the input slice the parser consumed, including any prefix and
+    /// closing parenthesis, e.g. `"($1.5MM)"`.
+    pub raw: String,
+}
+
 impl std::str::FromStr for Qty {
     type Err = String;
 
@@ -41,34 +175,104 @@ pub fn prefix<'s>(input: &mut &'s str) -> PResult<&'s str> {
 /// "1.5M" -> 1,500
 /// "1P" -> "1"
 /// "1000P" -> "1,000"
-
+/// "50bps" -> 0.005
+/// "2.5%" -> 0.025
+///
+/// `P` is "plain" (pass-through) by default; see [`PFactor`] to treat it as
+/// a divisor such as pence.
 pub fn multiplier(input: &mut &str) -> PResult<Option<Factor>> {
+    // Longest match first so "MM" isn't consumed as "M"
     opt(alt((
-        "M".map(|_| Factor::M),
-        "MM".map(|_| Factor::MM),
-        "MMM".map(|_| Factor::MMM),
         "MMMM".map(|_| Factor::MMMM),
+        "MMM".map(|_| Factor::MMM),
+        "MM".map(|_| Factor::MM),
+        "M".map(|_| Factor::M),
         'P'.map(|_| Factor::P),
+        Caseless("bps").map(|_| Factor::Bps),
+        '%'.map(|_| Factor::Pct),
     )))
     .parse_next(input)
 }
 
-pub fn parse_quantity(input: &mut &str) -> PResult<Qty> {
-    let (number, factor) = delimited(prefix, (digit1, multiplier), opt(')')).parse_next(input)?;
-    let value = number.parse::<f64>().unwrap();
-    Ok(Qty { value, factor })
+/// Like [`multiplier`], but a letter left over after the factor (or in place
+/// of one) is an error rather than silently ending the quantity.
+///
+/// # Example
+/// "1X" -> error
+/// "1MX" -> error
+pub fn strict_multiplier(input: &mut &str) -> PResult<Option<Factor>> {
+    terminated(multiplier, not(one_of(AsChar::is_alpha))).parse_next(input)
 }
 
-fn trivia<'s>(input: &mut &'s str) -> PResult<&'s str> {
-    take_while(0.., ('+', '-', '(', ')', '$', ' ', '\t')).parse_next(input)
+/// A number with optional digit grouping and decimal part, e.g. "1,000.5".
+///
+/// Grouping characters are dropped. It is an error for the decimal point to
+/// appear more than once or for grouping to follow it.
+pub fn number(style: DecimalStyle) -> impl FnMut(&mut &str) -> PResult<f64> {
+    let (decimal, group) = style.separators();
+    move |input: &mut &str| {
+        (
+            digit1,
+            take_while(0.., (AsChar::is_dec_digit, decimal, group)),
+        )
+            .recognize()
+            .verify(|s: &str| match (s.find(decimal), s.rfind(group)) {
+                (Some(d), Some(g)) => g < d,
+                _ => true,
+            })
+            .try_map(|s: &str| {
+                s.chars()
+                    .filter(|c| *c != group)
+                    .map(|c| if c == decimal { '.' } else { c })
+                    .collect::<String>()
+                    .parse::<f64>()
+            })
+            .parse_next(input)
+    }
 }
 
+pub fn parse_quantity(input: &mut &str) -> PResult<Qty> {
+    parse_quantity_with(&ParseConfig::default()).parse_next(input)
+}
 
-fn
+pub fn parse_quantity_with(config: &ParseConfig) -> impl FnMut(&mut &str) -> PResult<Qty> + '_ {
+    move |input: &mut &str| {
+        let (value, factor) = delimited(
+            prefix,
+            (
+                number(config.decimal_style),
+                strict_multiplier
+                    .verify(|f: &Option<Factor>| f.is_some() || !config.require_factor),
+            ),
+            opt(')'),
+        )
+        .parse_next(input)?;
+        Ok(Qty { value, factor })
+    }
+}
 
-fn number<'s>(input: &mut &'s str) -> PResult<&'s str> {
-    delimited(trivia, , trivia)
+/// Like [`parse_quantity`], but also keep the consumed text, including the
+/// prefix and closing parenthesis.
+///
+/// # Example
+/// "($1.5MM)" -> raw "($1.5MM)", scaled 1,500,000
+pub fn parse_quantity_with_raw(input: &mut &str) -> PResult<ParsedQty> {
+    parse_quantity
+        .with_recognized()
+        .map(|(qty, raw)| ParsedQty {
+            qty,
+            raw: raw.to_owned(),
+        })
+        .parse_next(input)
+}
+
+/// Leading punctuation and whitespace around a number. Not wired into the
+/// quantity parser yet, so only the tests use it.
+#[cfg(test)]
+fn trivia<'s>(input: &mut &'s str) -> PResult<&'s str> {
+    take_while(0.., ('+', '-', '(', ')', '$', ' ', '\t')).parse_next(input)
 }
+
 #[cfg(test)]
 mod tests {
     #[allow(dead_code)]
@@ -112,4 +316,154 @@ mod tests {
             })
         )
     }
+
+    #[test]
+    fn test_p_factor_plain() {
+        let qty: Qty = "1000P".parse().unwrap();
+        assert_eq!(qty.scaled(), 1000.0);
+    }
+
+    #[test]
+    fn test_p_factor_pence() {
+        let config = ParseConfig {
+            p_factor: PFactor::Divisor(100.0),
+            ..ParseConfig::default()
+        };
+        let qty: Qty = "150P".parse().unwrap();
+        assert_eq!(qty.scaled_with(&config), 1.5);
+        // Other factors are unaffected by the P interpretation
+        let qty: Qty = "2MM".parse().unwrap();
+        assert_eq!(qty.scaled_with(&config), 2_000_000.0);
+    }
+
+    #[test]
+    fn test_qty_is_copy() {
+        fn scale(qty: Qty) -> f64 {
+            qty.scaled()
+        }
+        let qty: Qty = "2M".parse().unwrap();
+        assert_eq!(scale(qty), 2000.0);
+        assert_eq!(qty.factor, Some(Factor::M));
+    }
+
+    #[test]
+    fn test_decimal_style_us() {
+        let config = ParseConfig::default();
+        let qty = Qty::parse_with("1,000.5", &config).unwrap();
+        assert_eq!(qty.scaled(), 1000.5);
+        let qty = Qty::parse_with("1.5M", &config).unwrap();
+        assert_eq!(qty.scaled(), 1500.0);
+        // Ambiguous: a decimal point in US style
+        let qty = Qty::parse_with("1.000", &config).unwrap();
+        assert_eq!(qty.scaled(), 1.0);
+        assert!(Qty::parse_with("1.000,5", &config).is_err());
+    }
+
+    #[test]
+    fn test_decimal_style_eu() {
+        let config = ParseConfig {
+            decimal_style: DecimalStyle::Eu,
+            ..ParseConfig::default()
+        };
+        let qty = Qty::parse_with("1.000,5", &config).unwrap();
+        assert_eq!(qty.scaled(), 1000.5);
+        // Ambiguous: digit grouping in EU style
+        let qty = Qty::parse_with("1.000", &config).unwrap();
+        assert_eq!(qty.scaled(), 1000.0);
+        let qty = Qty::parse_with("($2,5MM)", &config).unwrap();
+        assert_eq!(qty.scaled(), 2_500_000.0);
+        assert!(Qty::parse_with("1,000,5", &config).is_err());
+    }
+
+    #[test]
+    fn test_unknown_suffix() {
+        assert!(parse_quantity.parse_peek("1X").is_err());
+        assert!(parse_quantity.parse_peek("1MX").is_err());
+        assert!("1X".parse::<Qty>().is_err());
+        assert_eq!(
+            parse_quantity.parse_peek("1"),
+            Ok((
+                "",
+                Qty {
+                    value: 1.0,
+                    factor: None
+                }
+            ))
+        );
+        // The lenient combinator still stops before the unknown letter
+        assert_eq!(multiplier.parse_peek("X"), Ok(("X", None)));
+    }
+
+    #[test]
+    fn test_parse_quantity_with_raw() {
+        let parsed = parse_quantity_with_raw.parse("($1.5MM)").unwrap();
+        assert_eq!(parsed.raw, "($1.5MM)");
+        assert_eq!(parsed.qty.scaled(), 1_500_000.0);
+
+        // Only the consumed text is kept
+        let (rest, parsed) = parse_quantity_with_raw
+            .parse_peek(" 100 BBG000N88V36")
+            .unwrap();
+        assert_eq!(parsed.raw, " 100");
+        assert_eq!(rest, " BBG000N88V36");
+    }
+
+    #[test]
+    fn test_require_factor() {
+        let lenient = ParseConfig::default();
+        assert_eq!(Qty::parse_with("100", &lenient).unwrap().scaled(), 100.0);
+
+        let strict = ParseConfig {
+            require_factor: true,
+            ..ParseConfig::default()
+        };
+        assert!(Qty::parse_with("100", &strict).is_err());
+        assert!(Qty::parse_with("($100)", &strict).is_err());
+        assert_eq!(Qty::parse_with("100P", &strict).unwrap().scaled(), 100.0);
+        assert_eq!(
+            Qty::parse_with("1.5MM", &strict).unwrap().scaled(),
+            1_500_000.0
+        );
+    }
+
+    #[test]
+    fn test_bps_and_pct() -> Result<(), String> {
+        assert_eq!("50bps".parse::<Qty>()?.scaled(), 0.005);
+        assert_eq!("50BPS".parse::<Qty>()?.scaled(), 0.005);
+        assert_eq!("2.5%".parse::<Qty>()?.scaled(), 0.025);
+        assert_eq!("(25bps)".parse::<Qty>()?.scaled(), 0.0025);
+        assert!("50bp".parse::<Qty>().is_err());
+        assert!("50bpsX".parse::<Qty>().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_round_to() -> Result<(), String> {
+        let qty = |value| Qty {
+            value,
+            factor: None,
+        };
+        assert_ne!(0.1 + 0.2, 0.3);
+        assert_eq!(qty(0.1 + 0.2).round_to(2).scaled(), 0.3);
+
+        let tenth: Qty = "0.1".parse()?;
+        let sum = qty((0..10).map(|_| tenth.scaled()).sum());
+        assert_ne!(sum.scaled(), 1.0);
+        assert_eq!(sum.round_to(6).scaled(), 1.0);
+
+        // The factor is kept and applies after rounding
+        let qty_m: Qty = "1.2345M".parse()?;
+        assert_eq!(qty_m.round_to(2).scaled(), 1230.0);
+        assert_eq!(qty_m.round_to(2).factor, Some(Factor::M));
+
+        // Halves round away from zero, judged on the binary value
+        assert_eq!(qty(2.5).round_to(0).scaled(), 3.0);
+        assert_eq!(qty(0.125).round_to(2).scaled(), 0.13);
+        assert_eq!(qty(1.005).round_to(2).scaled(), 1.0);
+
+        // Nothing left to round
+        assert_eq!(qty(0.1).round_to(400), qty(0.1));
+        assert_eq!(qty(1e300).round_to(2), qty(1e300));
+        Ok(())
+    }
 }
@@ -0,0 +1,81 @@
+//! Conformance vectors for the check digit and structural rules, run against
+//! every FIGI implementation in the crate.
+//!
+//! Only [`ibrk_figi`] verifies the check digit; [`figi`] and
+//! [`figi_imperative`] must accept `checksum` vectors and reject `structural`
+//! ones.
+
+use symbology::ibrk_figi::InvalidFigi;
+use symbology::{figi, figi_imperative, ibrk_figi};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Expected {
+    Valid,
+    Checksum,
+    Structural,
+}
+
+fn vectors() -> Vec<(&'static str, Expected)> {
+    include_str!("data/figi_vectors.txt")
+        .lines()
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let (input, expected) = line.split_once(' ').expect("`<input> <expectation>`");
+            let expected = match expected {
+                "valid" => Expected::Valid,
+                "checksum" => Expected::Checksum,
+                "structural" => Expected::Structural,
+                other => panic!("unknown expectation {other:?} for {input}"),
+            };
+            (input, expected)
+        })
+        .collect()
+}
+
+#[test]
+fn vectors_are_bundled() {
+    let vectors = vectors();
+    let count = |e| vectors.iter().filter(|(_, x)| *x == e).count();
+    assert!(count(Expected::Valid) >= 12);
+    assert!(count(Expected::Checksum) > 0);
+    assert!(count(Expected::Structural) > 0);
+}
+
+#[test]
+fn ibrk_figi_matches_vectors() {
+    for (input, expected) in vectors() {
+        let result = input.parse::<ibrk_figi::Figi>();
+        match expected {
+            Expected::Valid => assert!(result.is_ok(), "{input}: {result:?}"),
+            Expected::Checksum => assert!(
+                matches!(result, Err(InvalidFigi::Checksum(_))),
+                "{input}: {result:?}"
+            ),
+            Expected::Structural => assert!(result.is_err(), "{input}: {result:?}"),
+        }
+    }
+}
+
+#[test]
+fn figi_matches_vectors() {
+    for (input, expected) in vectors() {
+        let result = input.parse::<figi::Figi>();
+        assert_eq!(
+            result.is_ok(),
+            expected != Expected::Structural,
+            "{input}: {result:?}"
+        );
+    }
+}
+
+#[test]
+fn figi_imperative_matches_vectors() {
+    for (input, expected) in vectors() {
+        let result = input.parse::<figi_imperative::Figi>();
+        assert_eq!(
+            result.is_ok(),
+            expected != Expected::Structural,
+            "{input}: {result:?}"
+        );
+    }
+}
@@ -0,0 +1,35 @@
+//! The batch APIs update the global counters. This is its own test binary so
+//! that no other test parses concurrently.
+
+#![cfg(feature = "metrics")]
+
+use symbology::ibrk_figi::{parse_list, validate_report, Figi, InvalidFigiKind};
+use symbology::metrics::{FigiMetrics, MetricsSnapshot};
+
+#[test]
+fn batch_apis_update_global_metrics() {
+    let metrics = FigiMetrics::global();
+    assert_eq!(metrics.snapshot(), MetricsSnapshot::default());
+
+    let inputs = [
+        "BBG000N88V36",
+        "BBG000BD8ZK0",
+        "BBG000N88V37",
+        "BSG000N88V36",
+    ];
+    let _ = Figi::par_parse_many(&inputs);
+    let after_batch = metrics.snapshot();
+    assert_eq!(after_batch.parsed, 2);
+    assert_eq!(after_batch.failed_with(InvalidFigiKind::Checksum), 1);
+    assert_eq!(after_batch.failed_with(InvalidFigiKind::FirstTwo), 1);
+    assert_eq!(after_batch.failed(), 2);
+
+    // parse_list stops at the first failure
+    let _ = parse_list("BBG000N88V36 BBG000N88V3 BBG000BD8ZK0");
+    let _ = validate_report(["BBX000N88V36"].into_iter());
+    let snapshot = metrics.snapshot();
+    assert_eq!(snapshot.parsed, 3);
+    assert_eq!(snapshot.failed_with(InvalidFigiKind::Length), 1);
+    assert_eq!(snapshot.failed_with(InvalidFigiKind::Third), 1);
+    assert_eq!(snapshot.total(), after_batch.total() + 3);
+}
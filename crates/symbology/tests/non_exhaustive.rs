@@ -0,0 +1,144 @@
+//! The error enums are `#[non_exhaustive]`, so downstream matches like these
+//! need a wildcard arm and keep compiling when variants are added.
+
+use symbology::error::ParseError;
+use symbology::figi::FigiDiagnostic;
+use symbology::figi_group::InvalidFigiGroup;
+use symbology::figi_imperative::FigiParseError;
+use symbology::ibrk_figi::{
+    Figi, InvalidFigi, InvalidFigiKind, InvalidOcrFigi, WriteCanonicalError,
+};
+
+fn invalid_figi_name(e: &InvalidFigi) -> &'static str {
+    match e {
+        InvalidFigi::Checksum(_) => "checksum",
+        InvalidFigi::FirstTwo(_) => "first_two",
+        InvalidFigi::Third(_) => "third",
+        InvalidFigi::Consonant(_) => "consonant",
+        InvalidFigi::ConsonantOrNumeric(_) => "consonant_or_numeric",
+        InvalidFigi::Length(_) => "length",
+        InvalidFigi::Confusable { .. } => "confusable",
+        _ => "other",
+    }
+}
+
+#[test]
+fn invalid_figi_matches_with_wildcard() {
+    let s = || String::from("BBG000N88V37");
+    let cases = [
+        (InvalidFigi::Checksum(s()), "checksum"),
+        (InvalidFigi::FirstTwo(s()), "first_two"),
+        (InvalidFigi::Third(s()), "third"),
+        (InvalidFigi::Consonant(s()), "consonant"),
+        (InvalidFigi::ConsonantOrNumeric(s()), "consonant_or_numeric"),
+        (InvalidFigi::Length(s()), "length"),
+        (
+            InvalidFigi::Confusable {
+                input: s(),
+                position: 1,
+                found: '\u{412}',
+            },
+            "confusable",
+        ),
+    ];
+    for (e, name) in cases {
+        assert_eq!(invalid_figi_name(&e), name);
+        let kind = match e.kind() {
+            InvalidFigiKind::Checksum => "checksum",
+            InvalidFigiKind::Length => "length",
+            _ => "other",
+        };
+        assert!(kind == name || kind == "other");
+    }
+}
+
+#[test]
+fn other_errors_match_with_wildcard() {
+    let figi: Figi = "BBG000N88V36".parse().unwrap();
+    let invalid = InvalidFigi::Length(String::new());
+
+    for e in [
+        InvalidOcrFigi::NoCandidate(invalid.clone()),
+        InvalidOcrFigi::Ambiguous(vec![figi]),
+    ] {
+        let name = match e {
+            InvalidOcrFigi::NoCandidate(_) => "no_candidate",
+            InvalidOcrFigi::Ambiguous(_) => "ambiguous",
+            _ => "other",
+        };
+        assert_ne!(name, "other");
+    }
+
+    for e in [
+        WriteCanonicalError::Invalid(invalid.clone()),
+        WriteCanonicalError::Write(std::fmt::Error),
+    ] {
+        let name = match e {
+            WriteCanonicalError::Invalid(_) => "invalid",
+            WriteCanonicalError::Write(_) => "write",
+            _ => "other",
+        };
+        assert_ne!(name, "other");
+    }
+
+    for e in [
+        ParseError::Figi(invalid.clone()),
+        ParseError::FigiOcr(InvalidOcrFigi::NoCandidate(invalid)),
+    ] {
+        let name = match e {
+            ParseError::Figi(_) => "figi",
+            ParseError::FigiOcr(_) => "figi_ocr",
+            _ => "other",
+        };
+        assert_ne!(name, "other");
+    }
+
+    for e in [
+        InvalidFigiGroup::CompositeIsExchangeLevel(figi),
+        InvalidFigiGroup::DuplicateExchangeLevel(figi),
+        InvalidFigiGroup::ShareClassReused(figi),
+    ] {
+        assert!(match e {
+            InvalidFigiGroup::CompositeIsExchangeLevel(f)
+            | InvalidFigiGroup::DuplicateExchangeLevel(f)
+            | InvalidFigiGroup::ShareClassReused(f) => f == figi,
+            _ => false,
+        });
+    }
+
+    let input = || String::from("BBB000N88V36");
+    for e in [
+        FigiParseError::InvalidLength(input()),
+        FigiParseError::InvalidFormat {
+            input: input(),
+            position: 3,
+        },
+        FigiParseError::InvalidComponent {
+            input: input(),
+            position: 3,
+        },
+        FigiParseError::InvalidChecksum(input()),
+    ] {
+        assert!(match e {
+            FigiParseError::InvalidLength(s) | FigiParseError::InvalidChecksum(s) => s == input(),
+            FigiParseError::InvalidFormat { input: s, .. }
+            | FigiParseError::InvalidComponent { input: s, .. } => s == input(),
+            _ => false,
+        });
+    }
+
+    for e in [
+        FigiDiagnostic::Length(11),
+        FigiDiagnostic::Position {
+            position: 3,
+            expected: "G",
+        },
+    ] {
+        let name = match e {
+            FigiDiagnostic::Length(_) => "length",
+            FigiDiagnostic::Position { .. } => "position",
+            _ => "other",
+        };
+        assert_ne!(name, "other");
+    }
+}
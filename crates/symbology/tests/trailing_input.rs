@@ -0,0 +1,99 @@
+//! Every entry point that takes a whole string rejects a valid FIGI followed
+//! by anything else. The winnow combinators ([`figi::figi`],
+//! [`figi::figi_lenient`], [`figi::parse_figi_generic`]) stop after the FIGI
+//! by design and are checked through their `*_complete` wrappers.
+
+use std::str::FromStr;
+use symbology::figi_dfa::FigiDfa;
+use symbology::{figi, figi_imperative, ibrk_figi};
+use winnow::prelude::*;
+
+const VALID: &str = "BBG000BLNNH6";
+
+const TRAILING: [&str; 5] = [
+    "BBG000BLNNH6EXTRA",
+    "BBG000BLNNH61",
+    "BBG000BLNNH6;",
+    "BBG000BLNNH6\0",
+    "BBG000BLNNH6\u{412}",
+];
+
+type EntryPoint = (&'static str, fn(&str) -> bool);
+
+type Combinator = (&'static str, fn(&mut &str) -> PResult<figi::Figi>);
+
+fn entry_points() -> Vec<EntryPoint> {
+    vec![
+        ("figi::Figi::from_str", |s| figi::Figi::from_str(s).is_ok()),
+        ("figi::Figi::from_str_lenient", |s| {
+            figi::Figi::from_str_lenient(s).is_ok()
+        }),
+        ("figi::Figi::parse_qualified", |s| {
+            figi::Figi::parse_qualified(s).is_ok()
+        }),
+        ("figi::parse_figi_verbose", |s| {
+            figi::parse_figi_verbose(s).is_ok()
+        }),
+        ("figi::figi_complete", |s| figi::figi_complete(s).is_ok()),
+        ("figi::figi_lenient_complete", |s| {
+            figi::figi_lenient_complete(s).is_ok()
+        }),
+        ("figi::parse_figi_generic_complete", |s| {
+            figi::parse_figi_generic_complete(s).is_ok()
+        }),
+        ("figi::validate_bytes", |s| {
+            figi::validate_bytes(s.as_bytes()).is_ok()
+        }),
+        ("ibrk_figi::Figi::from_str", |s| {
+            ibrk_figi::Figi::from_str(s).is_ok()
+        }),
+        ("ibrk_figi::Figi::new", |s| ibrk_figi::Figi::new(s).is_ok()),
+        ("ibrk_figi::Figi::from_str_ocr", |s| {
+            ibrk_figi::Figi::from_str_ocr(s).is_ok()
+        }),
+        ("ibrk_figi::classify", |s| {
+            !matches!(ibrk_figi::classify(s), ibrk_figi::FigiStatus::Structural(_))
+        }),
+        ("figi_imperative::Figi::from_str", |s| {
+            figi_imperative::Figi::from_str(s).is_ok()
+        }),
+        ("FigiDfa::validate", |s| FigiDfa::validate(s.as_bytes())),
+    ]
+}
+
+#[test]
+fn whole_string_entry_points_reject_trailing_input() {
+    for (name, accepts) in entry_points() {
+        assert!(accepts(VALID), "{name} rejects {VALID:?}");
+        for input in TRAILING {
+            assert!(!accepts(input), "{name} accepts {input:?}");
+        }
+    }
+}
+
+#[test]
+fn combinators_leave_trailing_input() {
+    let combinators: [Combinator; 3] = [
+        ("figi::figi", figi::figi),
+        ("figi::figi_lenient", figi::figi_lenient),
+        ("figi::parse_figi_generic", figi::parse_figi_generic),
+    ];
+    for (name, mut parser) in combinators {
+        for input in TRAILING {
+            let (rest, parsed) = parser
+                .parse_peek(input)
+                .unwrap_or_else(|e| panic!("{name} fails on {input:?}: {e:?}"));
+            assert_eq!(parsed.to_string(), VALID, "{name}");
+            assert_eq!(&input[VALID.len()..], rest, "{name}");
+        }
+    }
+}
+
+#[test]
+fn lenient_strips_only_surrounding_noise() {
+    // Whitespace and quotes are normalized away; other trailing input is not
+    for input in ["BBG000BLNNH6 ", "\"BBG000BLNNH6\"", "bbg000blnnh6\n"] {
+        assert!(figi::Figi::from_str_lenient(input).is_ok(), "{input:?}");
+        assert!(figi::figi_lenient_complete(input).is_err(), "{input:?}");
+    }
+}